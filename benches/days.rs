@@ -0,0 +1,121 @@
+use std::path::{Path, PathBuf};
+use std::{env, fs};
+
+use advent_of_code_2024::inputs;
+use advent_of_code_2024::registry;
+use advent_of_code_2024::registry::Day;
+use criterion::{Criterion, SamplingMode};
+use itertools::Itertools;
+
+/// Run the benchmark of every day, part and parsing step in the registry using
+/// ```shell
+/// $ cargo bench
+/// ```
+///
+/// Days are grouped as e.g. `2024-day07`, a single one can be run by filtering on it:
+/// ```shell
+/// $ cargo bench -- 2024-day07
+/// ```
+///
+/// Once done, the median time of everything measured so far is summarised in
+/// `target/criterion/summary.md`.
+fn main() {
+    let mut criterion = Criterion::default().configure_from_args();
+
+    registry::days()
+        .iter()
+        .for_each(|day| bench_day(&mut criterion, day));
+
+    criterion.final_summary();
+    write_summary();
+}
+
+fn bench_day(c: &mut Criterion, day: &Day) {
+    let Some(input) = inputs::load(day.year, day.day) else {
+        return;
+    };
+    let mut group = c.benchmark_group(group_name(day));
+    // Some parts take a good part of a second, flat sampling keeps those from taking minutes
+    group.sampling_mode(SamplingMode::Flat);
+
+    if let Some(parse) = day.parse {
+        group.bench_function("parse", |b| b.iter(|| parse(&input)));
+    }
+    group.bench_function("part1", |b| b.iter(|| (day.part_1)(&input)));
+    if let Some(part_2) = day.part_2 {
+        group.bench_function("part2", |b| b.iter(|| part_2(&input)));
+    }
+
+    group.finish();
+}
+
+fn group_name(day: &Day) -> String {
+    format!("{}-day{:0>2}", day.year, day.day)
+}
+
+const COLUMNS: [&str; 3] = ["parse", "part1", "part2"];
+
+fn write_summary() {
+    let criterion = criterion_dir();
+
+    let rows = registry::days()
+        .iter()
+        .filter_map(|day| {
+            let group = criterion.join(group_name(day));
+            let medians = COLUMNS.map(|column| median(&group.join(column)));
+
+            medians.iter().any(Option::is_some).then(|| {
+                format!(
+                    "| {} | {} | {} |",
+                    day.year,
+                    day.day,
+                    medians
+                        .iter()
+                        .map(|median| median.map_or("".to_string(), format_ns))
+                        .join(" | ")
+                )
+            })
+        })
+        .collect_vec();
+    if rows.is_empty() {
+        return;
+    }
+
+    let summary = format!(
+        "| Year | Day | Parse | Part 1 | Part 2 |\n|---:|---:|---:|---:|---:|\n{}\n",
+        rows.join("\n")
+    );
+    let path = criterion.join("summary.md");
+    match fs::write(&path, summary) {
+        Ok(()) => println!("Summary of median times written to {}", path.display()),
+        Err(e) => eprintln!("Could not write {}: {e}", path.display()),
+    }
+}
+
+fn criterion_dir() -> PathBuf {
+    env::var("CRITERION_HOME")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| {
+            env::var("CARGO_TARGET_DIR")
+                .map(PathBuf::from)
+                .unwrap_or_else(|_| Path::new(env!("CARGO_MANIFEST_DIR")).join("target"))
+                .join("criterion")
+        })
+}
+
+/// Median of the latest run of a benchmark in nanoseconds, as estimated by Criterion.
+fn median(benchmark: &Path) -> Option<f64> {
+    let estimates = fs::read_to_string(benchmark.join("new").join("estimates.json")).ok()?;
+    let estimates: serde_json::Value = serde_json::from_str(&estimates).ok()?;
+
+    estimates["median"]["point_estimate"].as_f64()
+}
+
+fn format_ns(ns: f64) -> String {
+    match ns {
+        ns if ns < 1e3 => format!("{ns:.1} ns"),
+        ns if ns < 1e6 => format!("{:.1} µs", ns / 1e3),
+        ns if ns < 1e9 => format!("{:.1} ms", ns / 1e6),
+        ns => format!("{:.2} s", ns / 1e9),
+    }
+}
@@ -0,0 +1,177 @@
+use crate::inputs::Inputs;
+use crate::json_file;
+use crate::json_file::JsonFileError;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::{fs, io};
+
+/// User whose inputs live at the root of the input cache, all others have a directory of their own.
+pub const DEFAULT_USER: &str = "default";
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail {
+        expected: String,
+    },
+    /// The right answer is not known (yet).
+    Missing,
+}
+
+/// Known answers of a single user, stored as JSON by year, day and part:
+/// ```json
+/// { "2024": { "1": { "1": "2113135", "2": "19097157" } } }
+/// ```
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Answers {
+    answers: BTreeMap<u16, BTreeMap<u8, BTreeMap<u8, String>>>,
+}
+
+impl Answers {
+    /// Answers in the file, none at all if there is no such file.
+    pub fn load(path: &Path) -> Result<Self, JsonFileError> {
+        json_file::load(path)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), JsonFileError> {
+        json_file::save(self, path)
+    }
+
+    pub fn get(&self, year: u16, day: u8, part: u8) -> Option<&str> {
+        self.answers
+            .get(&year)?
+            .get(&day)?
+            .get(&part)
+            .map(String::as_str)
+    }
+
+    pub fn set(&mut self, year: u16, day: u8, part: u8, answer: impl Into<String>) {
+        self.answers
+            .entry(year)
+            .or_default()
+            .entry(day)
+            .or_default()
+            .insert(part, answer.into());
+    }
+
+    pub fn check(&self, year: u16, day: u8, part: u8, answer: &str) -> Verdict {
+        match self.get(year, day, part) {
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.to_string(),
+            },
+            None => Verdict::Missing,
+        }
+    }
+}
+
+/// Someone's personal inputs, together with the answers belonging to them.
+pub struct User {
+    pub name: String,
+    pub inputs: Inputs,
+    pub answers: Answers,
+}
+
+impl User {
+    pub fn load(name: &str) -> Result<Self, JsonFileError> {
+        let inputs = match name {
+            DEFAULT_USER => Inputs::from_env(),
+            _ => Inputs::for_user(name),
+        };
+
+        Ok(Self {
+            name: name.to_string(),
+            inputs,
+            answers: Answers::load(&Self::answers_path(name))?,
+        })
+    }
+
+    /// The default user, followed by everyone else having an answers file.
+    pub fn all() -> Result<Vec<Self>, JsonFileError> {
+        let dir = answers_dir();
+        let mut names = match fs::read_dir(&dir) {
+            Ok(entries) => entries
+                .filter_map(Result::ok)
+                .filter_map(|entry| {
+                    let path = entry.path();
+                    let name = path.file_stem()?.to_str()?.to_string();
+                    (path.extension()? == "json" && name != DEFAULT_USER).then_some(name)
+                })
+                .collect(),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => vec![],
+            Err(e) => return Err(JsonFileError::io(&dir, e)),
+        };
+        names.sort();
+        names.insert(0, DEFAULT_USER.to_string());
+
+        names.iter().map(|name| Self::load(name)).collect()
+    }
+
+    pub fn answers_path(name: &str) -> PathBuf {
+        answers_dir().join(format!("{name}.json"))
+    }
+}
+
+/// Answer of the default user, which tests of a solution against their input check for,
+/// so it is only ever written down in their answers file.
+pub fn expected(year: u16, day: u8, part: u8) -> String {
+    Answers::load(&User::answers_path(DEFAULT_USER))
+        .unwrap()
+        .get(year, day, part)
+        .unwrap_or_else(|| panic!("no known answer for {year} day {day} part {part}"))
+        .to_string()
+}
+
+fn answers_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("answers")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{env, process};
+
+    #[test]
+    fn answers_round_trip() {
+        let path = env::temp_dir().join(format!("aoc-answers-{}.json", process::id()));
+        let _ = fs::remove_file(&path);
+
+        let mut answers = Answers::load(&path).unwrap();
+        assert_eq!(None, answers.get(2024, 1, 1));
+
+        answers.set(2024, 1, 1, "11");
+        answers.set(2024, 23, 2, "co,de,ka,ta");
+        answers.save(&path).unwrap();
+
+        let answers = Answers::load(&path).unwrap();
+        assert_eq!(Some("11"), answers.get(2024, 1, 1));
+        assert_eq!(Verdict::Pass, answers.check(2024, 23, 2, "co,de,ka,ta"));
+        assert_eq!(
+            Verdict::Fail {
+                expected: "11".to_string()
+            },
+            answers.check(2024, 1, 1, "12")
+        );
+        assert_eq!(Verdict::Missing, answers.check(2024, 1, 2, "31"));
+        assert!(fs::read_to_string(&path)
+            .unwrap()
+            .contains("\"23\": {\n      \"2\": \"co,de,ka,ta\""));
+    }
+
+    #[test]
+    fn answers_belong_to_registered_days() {
+        let answers = Answers::load(&User::answers_path(DEFAULT_USER)).unwrap();
+        assert_eq!(answers.get(2024, 1, 1), Some(expected(2024, 1, 1).as_str()));
+
+        answers.answers.iter().for_each(|(&year, days)| {
+            days.iter().for_each(|(&day, parts)| {
+                let registered = crate::registry::find(year, day).unwrap();
+                parts
+                    .keys()
+                    .for_each(|&part| assert!(registered.solver(part).is_some()));
+            })
+        });
+    }
+}
@@ -0,0 +1,434 @@
+use std::error::Error;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+use std::{env, process};
+
+use advent_of_code_2024::answers::{User, Verdict};
+use advent_of_code_2024::parallel;
+use advent_of_code_2024::parallel::Parallelism;
+use advent_of_code_2024::registry;
+use advent_of_code_2024::registry::Day;
+use advent_of_code_2024::timing;
+use advent_of_code_2024::timing::{Baseline, Comparison, Timings};
+use itertools::Itertools;
+
+/// Binary running every solved day against personal inputs, verifying the known answers.
+///
+/// # How to run
+///
+/// This will run all days for every user having an answers file under `answers/`:
+/// ```shell
+/// $ cargo run --release --bin runner
+/// ```
+///
+/// Days can be narrowed down by year and day, users by name:
+/// ```shell
+/// $ cargo run --release --bin runner -- --year 2024 --day 7 --user default --user alice
+/// ```
+///
+/// Inputs of the `default` user live in `inputs/`, those of any other user in `inputs/<name>/`.
+/// The process exits with an error if any answer turned out wrong.
+///
+/// With `--time` every part is instead run a number of times, after a warm-up (1 run by default),
+/// reporting its minimum, median and maximum time, parsing being timed on its own as well:
+/// ```shell
+/// $ cargo run --release --bin runner -- --time 20 --warm-up 3
+/// ```
+///
+/// Medians can be saved as a baseline, and compared to one later,
+/// exiting with an error if any got slower by more than the threshold (10% by default):
+/// ```shell
+/// $ cargo run --release --bin runner -- --time 20 --save-baseline baseline.json
+/// $ cargo run --release --bin runner -- --time 20 --baseline baseline.json --threshold 25
+/// ```
+///
+/// Days spread their work over a thread per CPU, `--threads` runs them serially (`serial`)
+/// or on a pool of a given size instead, for all days or for a single one (`DAY=...`).
+/// Comparing to a baseline shows what it changes:
+/// ```shell
+/// $ cargo run --release --bin runner -- --time 20 --threads serial --save-baseline serial.json
+/// $ cargo run --release --bin runner -- --time 20 --threads 4 --threads 7=serial --baseline serial.json
+/// ```
+fn main() {
+    let args: Vec<String> = env::args().collect();
+
+    let config = Config::build(&args).unwrap_or_else(|err| {
+        eprintln!("Problem parsing arguments: {err}");
+        process::exit(1);
+    });
+
+    match run(config) {
+        Ok(true) => {}
+        Ok(false) => process::exit(1),
+        Err(e) => {
+            eprintln!("Application error: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+struct Config {
+    year: Option<u16>,
+    day: Option<u8>,
+    users: Vec<String>,
+    parallelism: Parallelism,
+    /// Overrides of the parallelism for single days.
+    parallelism_per_day: Vec<(u8, Parallelism)>,
+    timing: Option<Timing>,
+}
+
+struct Timing {
+    runs: usize,
+    warm_up: usize,
+    baseline: Option<PathBuf>,
+    save_baseline: Option<PathBuf>,
+    /// Relative slowdown still accepted compared to the baseline.
+    threshold: f64,
+}
+
+impl Config {
+    fn build(args: &[String]) -> Result<Config, &'static str> {
+        let mut year = None;
+        let mut day = None;
+        let mut users = vec![];
+        let mut parallelism = Parallelism::default();
+        let mut parallelism_per_day = vec![];
+        let mut runs = None;
+        let mut warm_up = 1;
+        let mut baseline = None;
+        let mut save_baseline = None;
+        let mut threshold = 10.0;
+
+        let mut args = args.iter().skip(1);
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--year" => {
+                    year = Some(
+                        args.next()
+                            .and_then(|year| u16::from_str(year).ok())
+                            .ok_or("invalid year")?,
+                    )
+                }
+                "--day" => {
+                    day = Some(
+                        args.next()
+                            .and_then(|day| u8::from_str(day).ok())
+                            .ok_or("invalid day")?,
+                    )
+                }
+                "--user" => users.push(args.next().ok_or("missing user")?.to_string()),
+                "--threads" => {
+                    let threads = args.next().ok_or("missing threads")?;
+                    match threads.split_once('=') {
+                        Some((day, threads)) => parallelism_per_day.push((
+                            u8::from_str(day).map_err(|_| "invalid day for threads")?,
+                            Parallelism::from_str(threads)?,
+                        )),
+                        None => parallelism = Parallelism::from_str(threads)?,
+                    }
+                }
+                "--time" => {
+                    runs = Some(
+                        args.next()
+                            .and_then(|runs| usize::from_str(runs).ok())
+                            .filter(|&runs| runs > 0)
+                            .ok_or("invalid number of runs")?,
+                    )
+                }
+                "--warm-up" => {
+                    warm_up = args
+                        .next()
+                        .and_then(|warm_up| usize::from_str(warm_up).ok())
+                        .ok_or("invalid number of warm-up runs")?
+                }
+                "--baseline" => baseline = Some(args.next().ok_or("missing baseline")?.into()),
+                "--save-baseline" => {
+                    save_baseline = Some(args.next().ok_or("missing baseline")?.into())
+                }
+                "--threshold" => {
+                    threshold = args
+                        .next()
+                        .and_then(|threshold| f64::from_str(threshold).ok())
+                        .filter(|&threshold| threshold >= 0.0)
+                        .ok_or("invalid threshold percentage")?
+                }
+                _ => return Err("unknown argument"),
+            }
+        }
+
+        let timing = runs.map(|runs| Timing {
+            runs,
+            warm_up,
+            baseline,
+            save_baseline,
+            threshold: threshold / 100.0,
+        });
+
+        Ok(Config {
+            year,
+            day,
+            users,
+            parallelism,
+            parallelism_per_day,
+            timing,
+        })
+    }
+
+    fn parallelism(&self, day: &Day) -> Parallelism {
+        self.parallelism_per_day
+            .iter()
+            .rev()
+            .find(|(d, _)| *d == day.day)
+            .map_or(self.parallelism, |(_, parallelism)| *parallelism)
+    }
+
+    fn selects(&self, day: &Day) -> bool {
+        self.year.is_none_or(|year| year == day.year) && self.day.is_none_or(|d| d == day.day)
+    }
+}
+
+enum Outcome {
+    NoInput,
+    Solved {
+        answer: String,
+        verdict: Verdict,
+        time: Duration,
+    },
+}
+
+impl Outcome {
+    fn cell(&self) -> String {
+        match self {
+            Outcome::NoInput => "no input".to_string(),
+            Outcome::Solved { verdict, time, .. } => {
+                let status = match verdict {
+                    Verdict::Pass => "pass",
+                    Verdict::Fail { .. } => "FAIL",
+                    Verdict::Missing => "missing",
+                };
+                format!("{status:<8}{time:>10.2?}")
+            }
+        }
+    }
+}
+
+fn run(config: Config) -> Result<bool, Box<dyn Error>> {
+    let users = match config.users.is_empty() {
+        true => User::all()?,
+        false => config
+            .users
+            .iter()
+            .map(|name| User::load(name))
+            .collect::<Result<_, _>>()?,
+    };
+    if let Some(timing) = &config.timing {
+        return time(&config, timing, &users[0]);
+    }
+
+    let mut rows = vec![];
+    for day in registry::days().iter().filter(|day| config.selects(day)) {
+        parallel::set(config.parallelism(day))?;
+        let inputs = users
+            .iter()
+            .map(|user| user.inputs.get(day.year, day.day).ok())
+            .collect_vec();
+
+        for (part, solver) in [(1, Some(day.part_1)), (2, day.part_2)] {
+            let Some(solver) = solver else {
+                continue;
+            };
+
+            let outcomes = users
+                .iter()
+                .zip(&inputs)
+                .map(|(user, input)| match input {
+                    None => Outcome::NoInput,
+                    Some(input) => {
+                        let start = Instant::now();
+                        let answer = solver(input);
+                        let time = start.elapsed();
+
+                        Outcome::Solved {
+                            verdict: user.answers.check(day.year, day.day, part, &answer),
+                            answer,
+                            time,
+                        }
+                    }
+                })
+                .collect_vec();
+
+            rows.push((day, part, outcomes));
+        }
+    }
+
+    print_table(&users, &rows);
+    Ok(print_details(&users, &rows))
+}
+
+fn print_table(users: &[User], rows: &[(&Day, u8, Vec<Outcome>)]) {
+    const WIDTH: usize = 18;
+
+    let header = format!(
+        "{:<9}{:<6}{}",
+        "Day",
+        "Part",
+        users
+            .iter()
+            .map(|user| format!("{:<WIDTH$}", user.name))
+            .join("  ")
+    );
+    println!("{}", header.trim_end());
+
+    rows.iter().for_each(|(day, part, outcomes)| {
+        let row = format!(
+            "{:<9}{:<6}{}",
+            format!("{}-{:0>2}", day.year, day.day),
+            part,
+            outcomes
+                .iter()
+                .map(|outcome| format!("{:<WIDTH$}", outcome.cell()))
+                .join("  ")
+        );
+        println!("{}", row.trim_end());
+    });
+}
+
+/// Lists every answer that is not a pass, returns whether none of them were wrong.
+fn print_details(users: &[User], rows: &[(&Day, u8, Vec<Outcome>)]) -> bool {
+    let mut counts = [0; 4];
+    let mut details = vec![];
+
+    for (day, part, outcomes) in rows {
+        for (user, outcome) in users.iter().zip(outcomes) {
+            let name = format!("{} {}-{:0>2} part {}", user.name, day.year, day.day, part);
+            match outcome {
+                Outcome::NoInput => counts[3] += 1,
+                Outcome::Solved {
+                    answer, verdict, ..
+                } => match verdict {
+                    Verdict::Pass => counts[0] += 1,
+                    Verdict::Fail { expected } => {
+                        counts[1] += 1;
+                        details.push(format!("{name}: got {answer}, expected {expected}"));
+                    }
+                    Verdict::Missing => {
+                        counts[2] += 1;
+                        details.push(format!("{name}: got {answer}, answer unknown"));
+                    }
+                },
+            }
+        }
+    }
+
+    println!();
+    details.iter().for_each(|detail| println!("{detail}"));
+    println!(
+        "{} passed, {} failed, {} missing, {} without input",
+        counts[0], counts[1], counts[2], counts[3]
+    );
+
+    counts[1] == 0
+}
+
+/// Times every step of every day for a single user, returns whether none of them regressed.
+fn time(config: &Config, timing: &Timing, user: &User) -> Result<bool, Box<dyn Error>> {
+    let baseline = match &timing.baseline {
+        Some(path) => Some(Baseline::load(path)?),
+        None => None,
+    };
+    let mut measured = Baseline::default();
+    let mut regressions = 0;
+
+    println!(
+        "{:<9}{:<7}{:>11}{:>11}{:>11}{:>11}{:>9}",
+        "Day", "Step", "min", "median", "max", "solve", "change"
+    );
+    for day in registry::days().iter().filter(|day| config.selects(day)) {
+        parallel::set(config.parallelism(day))?;
+        let name = format!("{}-{:0>2}", day.year, day.day);
+        let Ok(input) = user.inputs.get(day.year, day.day) else {
+            println!("{name:<9}no input");
+            continue;
+        };
+
+        let parse = day
+            .parse
+            .and_then(|parse| timing::measure(timing.runs, timing.warm_up, || parse(&input)));
+        let parts = [(1, Some(day.part_1)), (2, day.part_2)]
+            .into_iter()
+            .filter_map(|(part, solver)| {
+                let solver = solver?;
+                let timings = timing::measure(timing.runs, timing.warm_up, || solver(&input))?;
+                Some((format!("part{part}"), timings))
+            })
+            .collect_vec();
+
+        let steps = parse
+            .map(|timings| ("parse".to_string(), timings))
+            .into_iter()
+            .chain(parts);
+        for (step, timings) in steps {
+            // Parts parse their input themselves, what is left is the time spent solving
+            let solve = match (&parse, step.as_str()) {
+                (_, "parse") => None,
+                (Some(parse), _) => Some(timings.median.saturating_sub(parse.median)),
+                (None, _) => Some(timings.median),
+            };
+            let comparison = baseline
+                .as_ref()
+                .map(|baseline| baseline.compare(&name, &step, timings.median, timing.threshold));
+            if let Some(Comparison::Regressed(_)) = comparison {
+                regressions += 1;
+            }
+
+            println!(
+                "{:<9}{:<7}{}",
+                name,
+                step,
+                timing_cells(&timings, solve, comparison)
+            );
+            measured.set(&name, &step, timings.median);
+        }
+    }
+
+    if let Some(path) = &timing.save_baseline {
+        measured.save(path)?;
+        println!("\nBaseline saved to {}", path.display());
+    }
+    if baseline.is_some() {
+        println!(
+            "\n{} regressed more than {:.0}%",
+            regressions,
+            timing.threshold * 100.0
+        );
+    }
+
+    Ok(regressions == 0)
+}
+
+fn timing_cells(
+    timings: &Timings,
+    solve: Option<Duration>,
+    comparison: Option<Comparison>,
+) -> String {
+    let format = |duration: Duration| format!("{duration:>11.2?}");
+    let solve = solve.map_or(format!("{:>11}", ""), format);
+    let comparison = match comparison {
+        None => String::new(),
+        Some(Comparison::New) => format!("{:>9}", "new"),
+        Some(Comparison::Within(change)) => format!("{:>+8.1}%", change * 100.0),
+        Some(Comparison::Regressed(change)) => format!("{:>+8.1}% REGRESSED", change * 100.0),
+    };
+
+    format!(
+        "{}{}{}{}{}",
+        format(timings.min),
+        format(timings.median),
+        format(timings.max),
+        solve,
+        comparison
+    )
+    .trim_end()
+    .to_string()
+}
@@ -2,31 +2,55 @@ use std::error::Error;
 use std::fs::File;
 use std::io::Write;
 use std::ops::Not;
+use std::path::Path;
 use std::str::FromStr;
-use std::{env, process};
+use std::{env, fs, process};
 
-use chrono::Datelike;
+use chrono::{Datelike, FixedOffset, NaiveDate, Utc};
+use itertools::Itertools;
+use regex::Regex;
 
 /// Binary to scaffold code for a new Advent of Code day.
 ///
 /// # How to run
 ///
-/// This will scaffold all code for the day it is ran on:
+/// This will scaffold all code for the day it is ran on,
+/// puzzles unlock at midnight UTC-5 so that is the timezone "today" is taken in:
 /// ```shell
 /// $ cargo run --bin scaffold
 /// ```
 ///
-/// This will scaffold all code for "Day 07":
+/// This will scaffold all code for "Day 07" of this year:
 /// ```shell
-/// $ cargo run --bin scaffold -- 7
+/// $ cargo run --bin scaffold -- --day 7
+/// ```
+///
+/// Any earlier event can be picked with `--year`, code for each event lives in its own
+/// module, e.g. `src/solutions/y2023/day_07.rs`:
+/// ```shell
+/// $ cargo run --bin scaffold -- --year 2023 --day 7
+/// ```
+///
+/// The generated solution takes `&[&str]` and returns `u32` by default,
+/// both can be chosen, with `--input str` taking the whole input as a single `&str`:
+/// ```shell
+/// $ cargo run --bin scaffold -- --day 7 --input str --returns u64
+/// ```
+///
+/// With `--dry-run` nothing gets written, the files that would be created or updated are listed:
+/// ```shell
+/// $ cargo run --bin scaffold -- --year 2023 --day 7 --dry-run
 /// ```
 ///
 /// Alternatively the binary can be built and called directly:
 /// ```shell
 /// $ cargo build --release
-/// $ ./target/release/scaffold 7
+/// $ ./target/release/scaffold --day 7
 /// ```
 ///
+/// When the problem description was saved beforehand as `problems/yYYYY/day_XX.html` (or `.txt`),
+/// the samples and their expected answers are extracted from its markup into the tests.
+///
 /// Optionally an environment variable `OVERWRITE` can be set,
 /// this will overwrite contents of existing files,
 /// normally existing files would throw an error.
@@ -45,45 +69,157 @@ fn main() {
 }
 
 struct Config {
-    day: u8,
+    year: i32,
+    day: u32,
+    input: InputKind,
+    returns: String,
     overwrite: bool,
+    dry_run: bool,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum InputKind {
+    Str,
+    Lines,
 }
 
 impl Config {
     fn build(args: &[String]) -> Result<Config, &'static str> {
-        let day = if args.len() >= 2 {
-            u8::from_str(&args[1])
-        } else {
-            Ok(chrono::prelude::Utc::now().day() as u8)
-        };
-        if day.is_err() {
-            return Err("invalid day");
+        let mut year = None;
+        let mut day = None;
+        let mut input = InputKind::Lines;
+        let mut returns = "u32".to_string();
+        let mut dry_run = false;
+
+        let mut args = args.iter().skip(1);
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--year" => {
+                    year = Some(
+                        args.next()
+                            .and_then(|year| i32::from_str(year).ok())
+                            .ok_or("invalid year")?,
+                    )
+                }
+                "--day" => {
+                    day = Some(
+                        args.next()
+                            .and_then(|day| u32::from_str(day).ok())
+                            .ok_or("invalid day")?,
+                    )
+                }
+                "--input" => {
+                    input = match args.next().map(String::as_str) {
+                        Some("str") => InputKind::Str,
+                        Some("lines") => InputKind::Lines,
+                        _ => return Err("input should be either \"str\" or \"lines\""),
+                    }
+                }
+                "--returns" => {
+                    returns = args
+                        .next()
+                        .filter(|returns| RETURN_TYPES.contains(&returns.as_str()))
+                        .ok_or("unsupported return type")?
+                        .to_string()
+                }
+                "--dry-run" => dry_run = true,
+                _ => return Err("unknown argument"),
+            }
+        }
+
+        let today = Utc::now().with_timezone(&unlock_timezone()).date_naive();
+        let year = year.unwrap_or(today.year());
+        let day = day.unwrap_or(today.day());
+
+        if year < FIRST_YEAR {
+            return Err("there was no event that year");
+        }
+        if !(1..=last_day(year)).contains(&day) {
+            return Err("day is not part of the event");
+        }
+        if NaiveDate::from_ymd_opt(year, 12, day).is_none_or(|unlock| unlock > today) {
+            return Err("puzzle is not unlocked yet");
         }
-        let day = day.unwrap();
 
         let overwrite = env::var("OVERWRITE").is_ok();
 
-        Ok(Config { day, overwrite })
+        Ok(Config {
+            year,
+            day,
+            input,
+            returns,
+            overwrite,
+            dry_run,
+        })
+    }
+}
+
+const FIRST_YEAR: i32 = 2015;
+
+/// Puzzles used to run until Christmas, since 2025 there are twelve of them.
+fn last_day(year: i32) -> u32 {
+    if year < 2025 {
+        25
+    } else {
+        12
     }
 }
 
+/// Puzzles unlock at midnight EST, every day of the event.
+fn unlock_timezone() -> FixedOffset {
+    FixedOffset::west_opt(5 * 3600).unwrap()
+}
+
+const RETURN_TYPES: [&str; 6] = ["u32", "u64", "usize", "i32", "i64", "String"];
+
 fn run(config: Config) -> Result<(), Box<dyn Error>> {
+    let year = format!("y{}", config.year);
     let day = format!("day_{:0>2}", config.day);
 
-    write_file(&config, &format!("./inputs/{}.txt", day), "")?;
-    write_file(&config, &format!("./problems/{}.txt", day), "")?;
+    let problem = ["html", "txt"]
+        .iter()
+        .map(|extension| format!("./problems/{}/{}.{}", year, day, extension))
+        .find(|path| Path::new(path).exists());
+    let samples = match &problem {
+        Some(problem) => extract_samples(&fs::read_to_string(problem)?),
+        None => {
+            write_file(&config, &format!("./problems/{}/{}.txt", year, day), "")?;
+            vec![]
+        }
+    };
+
     write_file(
         &config,
-        &format!("./src/solutions/{}.rs", day),
-        &TEMPLATE.replace("{}", &day),
+        &format!("./src/solutions/{}/{}.rs", year, day),
+        &solution(&config, &day, &samples),
     )?;
 
-    append_file("./src/solutions.rs", &format!("pub mod {};\n", day))?;
+    let module = format!("./src/solutions/{}.rs", year);
+    if Path::new(&module).exists().not() {
+        append_file(
+            &config,
+            "./src/solutions.rs",
+            &format!("pub mod {};\n", year),
+        )?;
+    }
+    append_file(&config, &module, &format!("pub mod {};\n", day))?;
+    register_day(&config, REGISTRY, &day)?;
 
     Ok(())
 }
 
 fn write_file(config: &Config, path: &str, content: &str) -> Result<(), Box<dyn Error>> {
+    if config.dry_run {
+        if Path::new(path).exists() && config.overwrite.not() {
+            return Err(format!("{path} already exists").into());
+        }
+        println!("create {path}");
+        return Ok(());
+    }
+
+    if let Some(parent) = Path::new(path).parent() {
+        fs::create_dir_all(parent)?;
+    }
     File::options()
         .create_new(config.overwrite.not())
         .write(true)
@@ -94,8 +230,14 @@ fn write_file(config: &Config, path: &str, content: &str) -> Result<(), Box<dyn
     Ok(())
 }
 
-fn append_file(path: &str, content: &str) -> Result<(), Box<dyn Error>> {
+fn append_file(config: &Config, path: &str, content: &str) -> Result<(), Box<dyn Error>> {
+    if config.dry_run {
+        println!("update {path}");
+        return Ok(());
+    }
+
     File::options()
+        .create(true)
         .append(true)
         .open(path)?
         .write_all(content.as_ref())?;
@@ -103,42 +245,257 @@ fn append_file(path: &str, content: &str) -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
-const TEMPLATE: &str = "\
-pub fn solve_1(X: &[&str]) -> u32 {
-    42
+/// Registry of runnable days, which the runner and the benchmarks go through.
+const REGISTRY: &str = "./src/registry.rs";
+
+/// Line in the registry above which new days get inserted.
+const REGISTRY_MARKER: &str = "// scaffold: new days go above this line";
+
+fn register_day(config: &Config, path: &str, day: &str) -> Result<(), Box<dyn Error>> {
+    if Path::new(path).exists().not() {
+        return Ok(());
+    }
+    if config.dry_run {
+        println!("update {path}");
+        return Ok(());
+    }
+
+    let registry = fs::read_to_string(path)?;
+    let Some(marker) = registry.find(REGISTRY_MARKER) else {
+        return Err(format!("registry {path} is missing the line \"{REGISTRY_MARKER}\"").into());
+    };
+    let indent = registry[..marker]
+        .rsplit('\n')
+        .next()
+        .unwrap_or_default()
+        .to_string();
+    let input = match config.input {
+        InputKind::Str => "input.trim()",
+        InputKind::Lines => "&lines(input)",
+    };
+    let entry = format!(
+        "Day::new({}, {}, |input| y{}::{}::solve_1({}).to_string(), None),\n{}",
+        config.year, config.day, config.year, day, input, indent
+    );
+
+    fs::write(
+        path,
+        format!("{}{}{}", &registry[..marker], entry, &registry[marker..]),
+    )?;
+
+    Ok(())
 }
 
-#[cfg(test)]
-mod tests {
-    use itertools::Itertools;
+/// Sample input with its expected answer, for either part.
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct Sample {
+    input: String,
+    answer: Option<String>,
+}
 
-    use super::*;
+/// Pulls the first example out of every part of a saved problem description.
+///
+/// Advent of Code marks examples up as `<pre><code>` blocks and highlights answers in
+/// `<code><em>`, the last highlight of a part being the answer for its example.
+fn extract_samples(problem: &str) -> Vec<Sample> {
+    let example = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap();
+    let answer = Regex::new(r"(?s)<code><em>(.*?)</em></code>").unwrap();
+
+    problem
+        .split("<article")
+        .skip(1)
+        .filter_map(|part| {
+            let input = example.captures(part)?.get(1)?.as_str();
+            let answer = answer
+                .captures_iter(part)
+                .last()
+                .map(|answer| unescape(answer.get(1).unwrap().as_str()));
+
+            Some(Sample {
+                input: unescape(input).trim_end_matches('\n').to_string(),
+                answer,
+            })
+        })
+        .collect()
+}
+
+fn unescape(html: &str) -> String {
+    let tags = Regex::new(r"<[^>]*>").unwrap();
+
+    tags.replace_all(html, "")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
 
+fn solution(config: &Config, day: &str, samples: &[Sample]) -> String {
+    let parameter = match config.input {
+        InputKind::Str => "&str",
+        InputKind::Lines => "&[&str]",
+    };
+    let placeholder = match config.returns.as_str() {
+        "String" => "String::new()",
+        _ => "42",
+    };
+
+    // Part 2 only gets a stub once its example is known, every stub needing a test calling it
+    let parts = if samples.len() > 1 { 2 } else { 1 };
+
+    let mut solution = (1..=parts)
+        .map(|part| {
+            format!(
+                "pub fn solve_{}(X: {}) -> {} {{\n    {}\n}}\n\n",
+                part, parameter, config.returns, placeholder
+            )
+        })
+        .join("");
+    solution.push_str("#[cfg(test)]\nmod tests {\n");
+    if config.input == InputKind::Lines {
+        solution.push_str("    use itertools::Itertools;\n\n");
+    }
+    solution.push_str("    use super::*;\n    use crate::{answers, inputs};\n");
+
+    for part in 1..=parts {
+        let sample = samples.get(part - 1);
+        solution.push_str(&sample_test(config, day, part, sample));
+        solution.push_str(&solution_test(config, day, part));
+    }
+
+    solution.push_str("}\n");
+    solution
+}
+
+fn sample_test(config: &Config, day: &str, part: usize, sample: Option<&Sample>) -> String {
+    let lines = match sample {
+        Some(sample) => sample.input.lines().map(escape).collect_vec(),
+        None => vec![String::new(); 10],
+    };
+    let sample_literal = match config.input {
+        InputKind::Lines => format!(
+            "vec![\n{}        ]",
+            lines
+                .iter()
+                .map(|line| format!("            \"{}\",\n", line))
+                .join("")
+        ),
+        InputKind::Str => format!(
+            "\"\\\n{}\\\n            \"",
+            lines
+                .iter()
+                .map(|line| format!("                {}", line))
+                .join("\\n\\\n")
+        ),
+    };
+    let expected = match sample.and_then(|sample| sample.answer.as_ref()) {
+        Some(answer) => literal(config, answer),
+        None => literal(config, "42"),
+    };
+    let argument = match config.input {
+        InputKind::Str => "sample",
+        InputKind::Lines => "&sample",
+    };
+
+    format!(
+        "
     #[test]
-    fn {}_part_01_sample() {
-        let sample = vec![
-            \"\",
-            \"\",
-            \"\",
-            \"\",
-            \"\",
-            \"\",
-            \"\",
-            \"\",
-            \"\",
-            \"\",
-        ];
+    fn {day}_part_0{part}_sample() {{
+        let sample = {sample_literal};
 
-        assert_eq!(42, solve_1(&sample));
+        assert_eq!({expected}, solve_{part}({argument}));
+    }}
+"
+    )
+}
+
+fn solution_test(config: &Config, day: &str, part: usize) -> String {
+    let year = config.year;
+    let number = config.day;
+    let (input, argument) = match config.input {
+        InputKind::Str => ("input.trim()", "input"),
+        InputKind::Lines => ("input.lines().collect_vec()", "&input"),
+    };
+
+    format!(
+        "
+    #[test]
+    fn {day}_part_0{part}_solution() {{
+        let Some(input) = inputs::load({year}, {number}) else {{
+            return;
+        }};
+        let input = {input};
+
+        assert_eq!(answers::expected({year}, {number}, {part}), solve_{part}({argument}).to_string());
+    }}
+"
+    )
+}
+
+/// Writes an answer the way the tests spell it, with `_` separating thousands.
+fn literal(config: &Config, answer: &str) -> String {
+    if config.returns == "String" || answer.parse::<i128>().is_err() {
+        return format!("\"{}\"", escape(answer));
+    }
+
+    let (sign, digits) = match answer.strip_prefix('-') {
+        Some(digits) => ("-", digits),
+        None => ("", answer),
+    };
+    let grouped = digits
+        .as_bytes()
+        .rchunks(3)
+        .rev()
+        .map(|chunk| std::str::from_utf8(chunk).unwrap())
+        .join("_");
+
+    format!("{}{}", sign, grouped)
+}
+
+fn escape(line: &str) -> String {
+    line.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> Config {
+        Config {
+            year: 2024,
+            day: 7,
+            input: InputKind::Str,
+            returns: String::from("u32"),
+            overwrite: false,
+            dry_run: true,
+        }
     }
 
     #[test]
-    fn {}_part_01_solution() {
-        let input = include_str!(\"../../inputs/{}.txt\")
-            .lines()
-            .collect_vec();
+    fn scaffold_solution() {
+        let samples = [
+            Sample {
+                input: String::from("1 2\n3 4"),
+                answer: Some(String::from("1234")),
+            },
+            Sample {
+                input: String::from("5 6"),
+                answer: Some(String::from("56")),
+            },
+        ];
+
+        let one = solution(&config(), "day_07", &samples[..1]);
+        assert!(one.contains("pub fn solve_1(X: &str) -> u32 {\n    42\n}"));
+        assert!(one.contains("pub fn solve_2").not());
+        assert!(one.contains("assert_eq!(1_234, solve_1(sample));"));
+        assert!(one.contains("day_07_part_02").not());
 
-        assert_eq!(0, solve_1(&input));
+        let two = solution(&config(), "day_07", &samples);
+        assert!(two.contains("pub fn solve_1(X: &str) -> u32 {\n    42\n}"));
+        assert!(two.contains("pub fn solve_2(X: &str) -> u32 {\n    42\n}"));
+        assert!(two.contains("                5 6\\\n"));
+        assert!(two.contains("assert_eq!(56, solve_2(sample));"));
+        assert!(two.contains("fn day_07_part_02_solution()"));
+        assert!(two.contains("answers::expected(2024, 7, 2), solve_2(input).to_string()"));
     }
 }
-";
@@ -0,0 +1,111 @@
+use std::error::Error;
+use std::str::FromStr;
+use std::{env, process};
+
+use advent_of_code_2024::answers::{User, DEFAULT_USER};
+use advent_of_code_2024::registry;
+use advent_of_code_2024::submit::{Outcome, Submitter};
+
+/// Binary submitting an answer to Advent of Code.
+///
+/// # How to run
+///
+/// This will solve part 1 of "Day 07" with the personal input, and submit its answer:
+/// ```shell
+/// $ cargo run --release --bin submit -- --year 2024 --day 7 --part 1
+/// ```
+///
+/// The answer can be given explicitly as well, and submitted for any user:
+/// ```shell
+/// $ cargo run --release --bin submit -- --year 2024 --day 7 --part 1 --user alice 3749
+/// ```
+///
+/// Submitting needs the session of the user in `AOC_SESSION` (or `AOC_SESSION_<NAME>`).
+/// Answers known to be wrong from earlier submissions are not sent again,
+/// correct ones get added to the user's answers file.
+fn main() {
+    let args: Vec<String> = env::args().collect();
+
+    let config = Config::build(&args).unwrap_or_else(|err| {
+        eprintln!("Problem parsing arguments: {err}");
+        process::exit(1);
+    });
+
+    if let Err(e) = run(config) {
+        eprintln!("Application error: {e}");
+        process::exit(1);
+    }
+}
+
+struct Config {
+    year: u16,
+    day: u8,
+    part: u8,
+    user: String,
+    answer: Option<String>,
+}
+
+impl Config {
+    fn build(args: &[String]) -> Result<Config, &'static str> {
+        let mut year = None;
+        let mut day = None;
+        let mut part = None;
+        let mut user = DEFAULT_USER.to_string();
+        let mut answer = None;
+
+        let mut args = args.iter().skip(1);
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--year" => year = args.next().and_then(|year| u16::from_str(year).ok()),
+                "--day" => day = args.next().and_then(|day| u8::from_str(day).ok()),
+                "--part" => part = args.next().and_then(|part| u8::from_str(part).ok()),
+                "--user" => user = args.next().ok_or("missing user")?.to_string(),
+                _ => answer = Some(arg.to_string()),
+            }
+        }
+
+        Ok(Config {
+            year: year.ok_or("missing or invalid year")?,
+            day: day.ok_or("missing or invalid day")?,
+            part: part
+                .filter(|part| (1..=2).contains(part))
+                .ok_or("part should be 1 or 2")?,
+            user,
+            answer,
+        })
+    }
+}
+
+fn run(config: Config) -> Result<(), Box<dyn Error>> {
+    let mut user = User::load(&config.user)?;
+
+    let answer = match config.answer {
+        Some(answer) => answer,
+        None => {
+            let solver = registry::find(config.year, config.day)
+                .and_then(|day| day.solver(config.part))
+                .ok_or("day is not solved yet, pass the answer explicitly")?;
+            solver(&user.inputs.get(config.year, config.day)?)
+        }
+    };
+
+    println!("Submitting {answer}");
+    let outcome =
+        Submitter::for_user(&user)?.submit(config.year, config.day, config.part, &answer)?;
+
+    match outcome {
+        Outcome::Correct => {
+            println!("Correct!");
+            user.answers
+                .set(config.year, config.day, config.part, answer);
+            user.answers.save(&User::answers_path(&user.name))?;
+        }
+        Outcome::TooHigh => println!("Wrong, too high"),
+        Outcome::TooLow => println!("Wrong, too low"),
+        Outcome::Wrong => println!("Wrong"),
+        Outcome::Wait(left) => println!("Submitted too recently, wait {}s", left.as_secs()),
+        Outcome::WrongLevel => println!("Part is already solved, or not unlocked yet"),
+    }
+
+    Ok(())
+}
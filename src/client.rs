@@ -0,0 +1,223 @@
+use std::fmt::{Display, Formatter};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use std::{env, error, io};
+
+#[cfg(test)]
+pub mod mock;
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// Environment variable holding the value of the `session` cookie of a logged-in user.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Advent of Code asks automated tools to identify themselves.
+const USER_AGENT: &str = "github.com/bram-inniger/advent-of-code-2024";
+
+/// Minimal HTTP needed to talk to Advent of Code, so the transport can be swapped in tests.
+///
+/// Any status code is a valid response, only failing to get one at all is an error.
+pub trait HttpClient {
+    fn get(&self, url: &str, session: &str) -> Result<Response, ClientError>;
+
+    fn post(
+        &self,
+        url: &str,
+        session: &str,
+        form: &[(&str, &str)],
+    ) -> Result<Response, ClientError>;
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+impl Response {
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+}
+
+#[derive(Debug)]
+pub enum ClientError {
+    Transport(String),
+    Io(io::Error),
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClientError::Transport(message) => write!(f, "request failed: {message}"),
+            ClientError::Io(e) => write!(f, "could not read response: {e}"),
+        }
+    }
+}
+
+impl error::Error for ClientError {}
+
+/// Session stored in the given environment variable, if it holds any.
+pub fn session_from_env(var: &str) -> Option<String> {
+    env::var(var)
+        .ok()
+        .map(|session| session.trim().to_string())
+        .filter(|session| !session.is_empty())
+}
+
+pub struct UreqClient {
+    agent: ureq::Agent,
+}
+
+impl UreqClient {
+    pub fn new() -> Self {
+        Self {
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+        }
+    }
+
+    fn read(response: Result<ureq::Response, ureq::Error>) -> Result<Response, ClientError> {
+        let response = match response {
+            Ok(response) => response,
+            Err(ureq::Error::Status(_, response)) => response,
+            Err(e) => return Err(ClientError::Transport(e.to_string())),
+        };
+
+        Ok(Response {
+            status: response.status(),
+            body: response.into_string().map_err(ClientError::Io)?,
+        })
+    }
+}
+
+impl Default for UreqClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HttpClient for UreqClient {
+    fn get(&self, url: &str, session: &str) -> Result<Response, ClientError> {
+        Self::read(
+            self.agent
+                .get(url)
+                .set("Cookie", &format!("session={session}"))
+                .call(),
+        )
+    }
+
+    fn post(
+        &self,
+        url: &str,
+        session: &str,
+        form: &[(&str, &str)],
+    ) -> Result<Response, ClientError> {
+        Self::read(
+            self.agent
+                .post(url)
+                .set("Cookie", &format!("session={session}"))
+                .send_form(form),
+        )
+    }
+}
+
+/// Spaces out the requests of the wrapped client by at least `interval`.
+///
+/// Requests are serialised, so clients shared between threads never burst either.
+pub struct RateLimited<C> {
+    client: C,
+    interval: Duration,
+    last_request: Mutex<Option<Instant>>,
+}
+
+impl<C: HttpClient> RateLimited<C> {
+    pub fn new(client: C, interval: Duration) -> Self {
+        Self {
+            client,
+            interval,
+            last_request: Mutex::new(None),
+        }
+    }
+
+    fn throttled<T>(&self, request: impl FnOnce(&C) -> T) -> T {
+        let mut last_request = self.last_request.lock().unwrap();
+
+        if let Some(elapsed) = last_request.map(|last| last.elapsed()) {
+            if elapsed < self.interval {
+                std::thread::sleep(self.interval - elapsed);
+            }
+        }
+
+        let response = request(&self.client);
+        *last_request = Some(Instant::now());
+
+        response
+    }
+}
+
+impl<C: HttpClient> HttpClient for RateLimited<C> {
+    fn get(&self, url: &str, session: &str) -> Result<Response, ClientError> {
+        self.throttled(|client| client.get(url, session))
+    }
+
+    fn post(
+        &self,
+        url: &str,
+        session: &str,
+        form: &[(&str, &str)],
+    ) -> Result<Response, ClientError> {
+        self.throttled(|client| client.post(url, session, form))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::mock::MockServer;
+    use super::*;
+
+    #[test]
+    fn client_sends_session_and_form() {
+        let server = MockServer::start(|request| match request.path.as_str() {
+            "/answer" => (200, request.body.clone()),
+            _ => (404, "not found".to_string()),
+        });
+        let client = UreqClient::new();
+
+        let response = client
+            .post(
+                &server.url("/answer"),
+                "abc",
+                &[("level", "1"), ("answer", "42")],
+            )
+            .unwrap();
+        assert_eq!(200, response.status);
+        assert_eq!("level=1&answer=42", response.body);
+
+        let response = client.get(&server.url("/missing"), "abc").unwrap();
+        assert_eq!(404, response.status);
+        assert!(!response.is_success());
+
+        let requests = server.requests();
+        assert_eq!(2, requests.len());
+        assert_eq!("POST", requests[0].method);
+        assert_eq!(Some("session=abc"), requests[0].header("cookie"));
+        assert_eq!(Some(USER_AGENT), requests[1].header("user-agent"));
+    }
+
+    #[test]
+    fn client_rate_limits() {
+        let server = MockServer::start(|_| (200, String::new()));
+        let client = RateLimited::new(UreqClient::new(), Duration::from_millis(100));
+
+        let start = Instant::now();
+        (0..3).for_each(|_| {
+            client.get(&server.url("/"), "abc").unwrap();
+        });
+
+        assert!(start.elapsed() >= Duration::from_millis(200));
+        assert_eq!(3, server.requests().len());
+    }
+}
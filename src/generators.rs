@@ -0,0 +1,1003 @@
+//! Random, but valid, puzzle inputs of 2024, to stress test solutions and compare them with
+//! reference implementations. Inputs are formatted the way they get downloaded.
+
+use crate::solutions::y2024::day_14::Room;
+use itertools::Itertools;
+use rand::seq::{IndexedRandom, SliceRandom};
+use rand::Rng;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// Both lists of `nr_pairs` location IDs below `max_id`, the right one often repeating IDs
+/// of the left one so they are similar.
+pub fn day_01(rng: &mut impl Rng, nr_pairs: usize, max_id: i32) -> String {
+    let left = (0..nr_pairs)
+        .map(|_| rng.random_range(0..max_id))
+        .collect_vec();
+    let right = (0..nr_pairs)
+        .map(|_| match left.choose(rng) {
+            Some(&id) if rng.random_bool(0.5) => id,
+            _ => rng.random_range(0..max_id),
+        })
+        .collect_vec();
+
+    left.iter()
+        .zip(&right)
+        .map(|(left, right)| format!("{left}   {right}\n"))
+        .join("")
+}
+
+/// `nr_reports` reports of 5 to 8 levels, most of them gradually increasing or decreasing,
+/// some with a single level out of line, the rest anything at all.
+pub fn day_02(rng: &mut impl Rng, nr_reports: usize) -> String {
+    (0..nr_reports)
+        .map(|_| {
+            let len = rng.random_range(5..=8);
+            let sign = if rng.random_bool(0.5) { 1 } else { -1 };
+            let mut level = rng.random_range(30..70);
+            let mut report = (0..len)
+                .map(|_| {
+                    level += sign * rng.random_range(1..=3);
+                    level
+                })
+                .collect_vec();
+
+            match rng.random_range(0..3) {
+                0 => {}
+                1 => report[rng.random_range(0..len)] = rng.random_range(1..100),
+                _ => report
+                    .iter_mut()
+                    .for_each(|l| *l = rng.random_range(1..100)),
+            }
+
+            report.iter().join(" ") + "\n"
+        })
+        .join("")
+}
+
+/// Corrupted memory of `nr_instructions` instructions, `mul`, `do` and `don't`, some of them
+/// mangled, with garbage around and between them.
+pub fn day_03(rng: &mut impl Rng, nr_instructions: usize) -> String {
+    const GARBAGE: &[&str] = &[
+        "%", "&", "!", "@", "^", "*", "[", "]", "(", ")", "<", ">", ",", ":", ";", "'", "?", " ",
+        "+", "-", "from", "select", "where", "who", "why", "what", "how", "mul",
+    ];
+
+    (0..nr_instructions)
+        .map(|_| {
+            let instruction = match rng.random_range(0..10) {
+                0 => "do()".to_string(),
+                1 => "don't()".to_string(),
+                2 => format!(
+                    "mul({},{}]",
+                    rng.random_range(0..1000),
+                    rng.random_range(0..1000)
+                ),
+                3 => format!(
+                    "mul ( {} , {} )",
+                    rng.random_range(0..1000),
+                    rng.random_range(0..1000)
+                ),
+                4 => format!(
+                    "mul({},{})",
+                    rng.random_range(1000..10000),
+                    rng.random_range(0..1000)
+                ),
+                _ => format!(
+                    "mul({},{})",
+                    rng.random_range(0..1000),
+                    rng.random_range(0..1000)
+                ),
+            };
+            let garbage = (0..rng.random_range(0..4))
+                .map(|_| *GARBAGE.choose(rng).unwrap())
+                .join("");
+
+            garbage + &instruction
+        })
+        .join("")
+        + "\n"
+}
+
+/// Word search of `width` by `height` letters, all of them from "XMAS".
+pub fn day_04(rng: &mut impl Rng, width: usize, height: usize) -> String {
+    let letters = ['X', 'M', 'A', 'S'];
+    let grid = (0..height)
+        .map(|_| {
+            (0..width)
+                .map(|_| *letters.choose(rng).unwrap())
+                .collect_vec()
+        })
+        .collect_vec();
+
+    render(&grid)
+}
+
+/// Ordering rules between all of `nr_pages` pages (at most 90), and `nr_updates` updates of an odd
+/// number of them, half of which are in order.
+///
+/// Rules are given for every pair of pages, so any update has a single correct order.
+pub fn day_05(rng: &mut impl Rng, nr_pages: usize, nr_updates: usize) -> String {
+    assert!((3..=90).contains(&nr_pages), "pages are numbered 10 to 99");
+
+    let mut pages = (10..100).collect_vec();
+    pages.shuffle(rng);
+    pages.truncate(nr_pages);
+
+    let mut rules = pages
+        .iter()
+        .tuple_combinations()
+        .map(|(before, after)| format!("{before}|{after}"))
+        .collect_vec();
+    rules.shuffle(rng);
+
+    let updates = (0..nr_updates)
+        .map(|_| {
+            let len = 2 * rng.random_range(1..=(nr_pages - 1) / 2) + 1;
+            let mut update = pages
+                .iter()
+                .copied()
+                .enumerate()
+                .collect_vec()
+                .choose_multiple(rng, len)
+                .copied()
+                .collect_vec();
+            if rng.random_bool(0.5) {
+                update.sort();
+            }
+
+            update.iter().map(|(_, page)| page).join(",")
+        })
+        .join("\n");
+
+    format!("{}\n\n{}\n", rules.join("\n"), updates)
+}
+
+/// Lab of `width` by `height` with a guard facing up, about `density` of it obstructed.
+///
+/// The guard is guaranteed to leave the lab, so `density` is best kept below a third.
+pub fn day_06(rng: &mut impl Rng, width: usize, height: usize, density: f64) -> String {
+    loop {
+        let mut lab = vec![vec!['.'; width]; height];
+        lab.iter_mut().flatten().for_each(|tile| {
+            if rng.random_bool(density) {
+                *tile = '#';
+            }
+        });
+        let (x, y) = (rng.random_range(0..width), rng.random_range(0..height));
+        lab[y][x] = '^';
+
+        if guard_leaves(&lab, x, y) {
+            return render(&lab);
+        }
+    }
+}
+
+fn guard_leaves(lab: &[Vec<char>], x: usize, y: usize) -> bool {
+    let (mut x, mut y) = (x as i64, y as i64);
+    let (mut dx, mut dy) = (0, -1);
+    let mut visited = vec![[false; 4]; lab.len() * lab[0].len()];
+
+    loop {
+        let direction = match (dx, dy) {
+            (0, -1) => 0,
+            (1, 0) => 1,
+            (0, 1) => 2,
+            _ => 3,
+        };
+        let idx = y as usize * lab[0].len() + x as usize;
+        if visited[idx][direction] {
+            return false;
+        }
+        visited[idx][direction] = true;
+
+        let (next_x, next_y) = (x + dx, y + dy);
+        if !(0..lab[0].len() as i64).contains(&next_x) || !(0..lab.len() as i64).contains(&next_y) {
+            return true;
+        }
+        if lab[next_y as usize][next_x as usize] == '#' {
+            (dx, dy) = (-dy, dx);
+        } else {
+            (x, y) = (next_x, next_y);
+        }
+    }
+}
+
+/// `nr_equations` calibration equations of up to `max_operands` operands (at least 2), the test value
+/// combining them with random operators, or off by one to leave some unsolvable.
+pub fn day_07(rng: &mut impl Rng, nr_equations: usize, max_operands: usize) -> String {
+    assert!(max_operands >= 2, "an equation needs at least 2 operands");
+
+    let mut equations = vec![];
+    while equations.len() < nr_equations {
+        let operands = (0..rng.random_range(2..=max_operands))
+            .map(|_| rng.random_range(1..100u64))
+            .collect_vec();
+        let value = operands[1..]
+            .iter()
+            .try_fold(operands[0], |value, &operand| {
+                match rng.random_range(0..3) {
+                    0 => value.checked_add(operand),
+                    1 => value.checked_mul(operand),
+                    _ => format!("{value}{operand}").parse().ok(),
+                }
+            });
+        let Some(value) = value.and_then(|value| value.checked_add(rng.random_bool(0.25) as u64))
+        else {
+            continue;
+        };
+
+        equations.push(format!("{}: {}\n", value, operands.iter().join(" ")));
+    }
+
+    equations.join("")
+}
+
+/// City of `width` by `height` with `nr_antennas` antennas, spread over `nr_frequencies`
+/// frequencies (at most 62, as letters and digits).
+pub fn day_08(
+    rng: &mut impl Rng,
+    width: usize,
+    height: usize,
+    nr_frequencies: usize,
+    nr_antennas: usize,
+) -> String {
+    let frequencies = ('0'..='9')
+        .chain('a'..='z')
+        .chain('A'..='Z')
+        .take(nr_frequencies.clamp(1, 62))
+        .collect_vec();
+    let mut city = vec![vec!['.'; width]; height];
+
+    for _ in 0..nr_antennas.min(width * height) {
+        let (x, y) = loop {
+            let (x, y) = (rng.random_range(0..width), rng.random_range(0..height));
+            if city[y][x] == '.' {
+                break (x, y);
+            }
+        };
+        city[y][x] = *frequencies.choose(rng).unwrap();
+    }
+
+    render(&city)
+}
+
+/// Dense disk map of `nr_files` files, of 1 to 9 blocks each, with up to 9 free blocks between them.
+pub fn day_09(rng: &mut impl Rng, nr_files: usize) -> String {
+    (0..nr_files)
+        .flat_map(|idx| {
+            let file = rng.random_range(1..10);
+            let free = (idx + 1 < nr_files).then(|| rng.random_range(0..10));
+            [Some(file), free]
+        })
+        .flatten()
+        .map(|digit| char::from_digit(digit, 10).unwrap())
+        .chain(std::iter::once('\n'))
+        .collect()
+}
+
+/// Topographic map of random heights, with `nr_trails` hiking trails from 0 to 9 laid over it,
+/// fewer when the map is too crowded to fit them all.
+pub fn day_10(rng: &mut impl Rng, width: usize, height: usize, nr_trails: usize) -> String {
+    let mut map = (0..height)
+        .map(|_| {
+            (0..width)
+                .map(|_| char::from_digit(rng.random_range(0..10), 10).unwrap())
+                .collect_vec()
+        })
+        .collect_vec();
+
+    // Trails never cross, so laying one can't break another
+    let mut on_trail = vec![vec![false; width]; height];
+    let mut laid = 0;
+    for _ in 0..nr_trails * 100 {
+        if laid == nr_trails {
+            break;
+        }
+        let mut trail = vec![(rng.random_range(0..width), rng.random_range(0..height))];
+        while trail.len() < 10 {
+            let (x, y) = *trail.last().unwrap();
+            let free = neighbours(x, y, width, height)
+                .filter(|&(x, y)| !on_trail[y][x] && !trail.contains(&(x, y)))
+                .collect_vec();
+            match free.choose(rng) {
+                Some(&next) => trail.push(next),
+                None => break,
+            }
+        }
+        if trail.len() < 10 || on_trail[trail[0].1][trail[0].0] {
+            continue;
+        }
+
+        for (h, &(x, y)) in trail.iter().enumerate() {
+            on_trail[y][x] = true;
+            map[y][x] = char::from_digit(h as u32, 10).unwrap();
+        }
+        laid += 1;
+    }
+
+    render(&map)
+}
+
+/// `nr_stones` stones, engraved with numbers of up to `max_digits` digits.
+pub fn day_11(rng: &mut impl Rng, nr_stones: usize, max_digits: u32) -> String {
+    (0..nr_stones)
+        .map(|_| rng.random_range(0..10u64.pow(max_digits)))
+        .join(" ")
+        + "\n"
+}
+
+/// Garden planted with `nr_plants` kinds of plants, each plot being the same as the one left
+/// or above it with a `clumping` probability, forming larger and oddly shaped regions.
+pub fn day_12(
+    rng: &mut impl Rng,
+    width: usize,
+    height: usize,
+    nr_plants: u8,
+    clumping: f64,
+) -> String {
+    let nr_plants = nr_plants.clamp(1, 26);
+    let mut garden = vec![vec!['A'; width]; height];
+
+    for y in 0..height {
+        for x in 0..width {
+            garden[y][x] = match (x, y) {
+                (x, _) if x > 0 && rng.random_bool(clumping / 2.0) => garden[y][x - 1],
+                (_, y) if y > 0 && rng.random_bool(clumping) => garden[y - 1][x],
+                _ => (b'A' + rng.random_range(0..nr_plants)) as char,
+            };
+        }
+    }
+
+    render(&garden)
+}
+
+/// `nr_machines` claw machines, most of them with a prize the buttons can reach in at most 100
+/// pushes each.
+pub fn day_13(rng: &mut impl Rng, nr_machines: usize) -> String {
+    (0..nr_machines)
+        .map(|_| {
+            let [a_x, a_y, b_x, b_y] = [(); 4].map(|_| rng.random_range(10..100));
+            let (x, y) = if rng.random_bool(0.75) {
+                let (a, b) = (rng.random_range(0..=100), rng.random_range(0..=100));
+                (a * a_x + b * b_x, a * a_y + b * b_y)
+            } else {
+                (
+                    rng.random_range(1_000..20_000),
+                    rng.random_range(1_000..20_000),
+                )
+            };
+
+            format!("Button A: X+{a_x}, Y+{a_y}\nButton B: X+{b_x}, Y+{b_y}\nPrize: X={x}, Y={y}\n")
+        })
+        .join("\n")
+}
+
+/// `nr_robots` robots anywhere in the room, moving at any speed up to the size of the room.
+pub fn day_14(rng: &mut impl Rng, room: &Room, nr_robots: usize) -> String {
+    (0..nr_robots)
+        .map(|_| {
+            format!(
+                "p={},{} v={},{}\n",
+                rng.random_range(0..room.width),
+                rng.random_range(0..room.height),
+                rng.random_range(-room.width + 1..room.width),
+                rng.random_range(-room.height + 1..room.height),
+            )
+        })
+        .join("")
+}
+
+/// Warehouse of `width` by `height` surrounded by walls, about `density` of it boxes and a tenth
+/// of that walls, and `nr_moves` moves for the robot, 70 to a line.
+pub fn day_15(
+    rng: &mut impl Rng,
+    width: usize,
+    height: usize,
+    density: f64,
+    nr_moves: usize,
+) -> String {
+    let mut warehouse = vec![vec!['#'; width]; height];
+    warehouse[1..height - 1]
+        .iter_mut()
+        .flat_map(|row| &mut row[1..width - 1])
+        .for_each(|tile| {
+            *tile = match rng.random::<f64>() {
+                r if r < density => 'O',
+                r if r < density * 1.1 => '#',
+                _ => '.',
+            }
+        });
+    warehouse[rng.random_range(1..height - 1)][rng.random_range(1..width - 1)] = '@';
+
+    let moves = (0..nr_moves)
+        .map(|_| *['<', '>', '^', 'v'].choose(rng).unwrap())
+        .chunks(70)
+        .into_iter()
+        .map(|line| line.collect::<String>() + "\n")
+        .join("");
+
+    format!("{}\n{}", render(&warehouse), moves)
+}
+
+/// Reindeer maze from the bottom left to the top right corner, of `width` by `height` (both odd),
+/// knocking out about `loops` of the inner walls so multiple paths lead to the end.
+pub fn day_16(rng: &mut impl Rng, width: usize, height: usize, loops: f64) -> String {
+    let mut maze = maze(rng, width, height);
+
+    for y in 1..height - 1 {
+        for x in 1..width - 1 {
+            let between_horizontal = maze[y][x - 1] != '#' && maze[y][x + 1] != '#';
+            let between_vertical = maze[y - 1][x] != '#' && maze[y + 1][x] != '#';
+            if maze[y][x] == '#'
+                && (between_horizontal ^ between_vertical)
+                && rng.random_bool(loops)
+            {
+                maze[y][x] = '.';
+            }
+        }
+    }
+    maze[height - 2][1] = 'S';
+    maze[1][width - 2] = 'E';
+
+    render(&maze)
+}
+
+/// Program of `nr_instructions` instructions (at least 3) that outputs a number per 3 bits of
+/// register A, like the puzzle's do, and some value in register A to run it with.
+///
+/// Every loop shifts A by 3 bits once, and sets B (and C when used) from A before mixing them,
+/// so each output only depends on the bits of A left at that point.
+pub fn day_17(rng: &mut impl Rng, nr_instructions: usize) -> String {
+    assert!(
+        nr_instructions >= 3,
+        "a program loops with at least 3 instructions"
+    );
+
+    let mut body = vec![];
+    if nr_instructions == 3 {
+        body.push([5, 4]);
+    } else {
+        body.push([2, 4]);
+        let mut c_set = false;
+        for _ in 0..nr_instructions - 4 {
+            body.push(match rng.random_range(0..3) {
+                0 => [1, rng.random_range(0..8)],
+                1 if c_set => [4, rng.random_range(0..8)],
+                _ => {
+                    c_set = true;
+                    [7, 5]
+                }
+            });
+        }
+        body.push([5, if c_set { 6 } else { 5 }]);
+    }
+    body.insert(rng.random_range(0..=body.len()), [0, 3]);
+    body.push([3, 0]);
+
+    format!(
+        "Register A: {}\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n",
+        rng.random_range(1..1 << 30),
+        body.iter().flatten().join(",")
+    )
+}
+
+/// Every byte of a memory space from `0,0` to `max_dim,max_dim` falling in random order,
+/// apart from both corners, so the exit is sure to get cut off along the way.
+pub fn day_18(rng: &mut impl Rng, max_dim: i32) -> String {
+    let mut bytes = (0..=max_dim)
+        .cartesian_product(0..=max_dim)
+        .filter(|&tile| tile != (0, 0) && tile != (max_dim, max_dim))
+        .collect_vec();
+    bytes.shuffle(rng);
+
+    bytes.iter().map(|(x, y)| format!("{x},{y}\n")).join("")
+}
+
+/// `nr_patterns` distinct towel patterns of up to 3 stripes, and `nr_designs` designs,
+/// half of them made from those patterns and the other half any stripes at all.
+pub fn day_19(rng: &mut impl Rng, nr_patterns: usize, nr_designs: usize) -> String {
+    let colours = ['w', 'u', 'b', 'r', 'g'];
+    let stripes = |rng: &mut _, len| {
+        (0..len)
+            .map(|_| *colours.choose(rng).unwrap())
+            .collect::<String>()
+    };
+
+    // 5 + 25 + 125 patterns exist of up to 3 stripes
+    let mut patterns = HashSet::new();
+    while patterns.len() < nr_patterns.min(155) {
+        let len = rng.random_range(1..=3);
+        patterns.insert(stripes(rng, len));
+    }
+    let patterns = patterns.into_iter().sorted().collect_vec();
+
+    let designs = (0..nr_designs)
+        .map(|idx| match idx % 2 {
+            0 => (0..rng.random_range(1..=8))
+                .map(|_| patterns.choose(rng).unwrap().as_str())
+                .collect::<String>(),
+            _ => {
+                let len = rng.random_range(5..=20);
+                stripes(rng, len)
+            }
+        })
+        .join("\n");
+
+    format!("{}\n\n{}\n", patterns.join(", "), designs)
+}
+
+/// Racetrack of `width` by `height` (both odd), a single path without any branches.
+///
+/// The track is the longest path through a random maze, winding through most of the grid.
+pub fn day_20(rng: &mut impl Rng, width: usize, height: usize) -> String {
+    let maze = maze(rng, width, height);
+
+    let start = (1, height - 2);
+    let from = bfs(&maze, start);
+    let end = *from
+        .iter()
+        .max_by_key(|(_, distance, _)| *distance)
+        .map(|(end, _, _)| end)
+        .unwrap();
+
+    let previous: HashMap<_, _> = from
+        .iter()
+        .map(|&(tile, _, previous)| (tile, previous))
+        .collect();
+    let mut track = vec![vec!['#'; width]; height];
+    let mut tile = end;
+    loop {
+        track[tile.1][tile.0] = '.';
+        match previous.get(&tile).copied().flatten() {
+            Some(previous) => tile = previous,
+            None => break,
+        }
+    }
+    track[start.1][start.0] = 'S';
+    track[end.1][end.0] = 'E';
+
+    render(&track)
+}
+
+/// `nr_codes` door codes of three digits followed by `A`.
+pub fn day_21(rng: &mut impl Rng, nr_codes: usize) -> String {
+    (0..nr_codes)
+        .map(|_| format!("{:0>3}A\n", rng.random_range(0..1000)))
+        .join("")
+}
+
+/// Initial secret numbers of `nr_buyers` buyers.
+pub fn day_22(rng: &mut impl Rng, nr_buyers: usize) -> String {
+    (0..nr_buyers)
+        .map(|_| format!("{}\n", rng.random_range(1..1 << 24)))
+        .join("")
+}
+
+/// Network of `nr_computers` computers (at most 676) with `nr_connections` random connections,
+/// on top of a LAN party of `party` computers all connected to each other.
+pub fn day_23(
+    rng: &mut impl Rng,
+    nr_computers: usize,
+    nr_connections: usize,
+    party: usize,
+) -> String {
+    let mut computers = ('a'..='z')
+        .cartesian_product('a'..='z')
+        .map(|(first, second)| format!("{first}{second}"))
+        .collect_vec();
+    computers.shuffle(rng);
+    computers.truncate(nr_computers);
+    assert!(
+        (2..=computers.len()).contains(&party),
+        "a party needs at least 2 computers"
+    );
+
+    let mut connections = computers[..party]
+        .iter()
+        .tuple_combinations()
+        .collect::<HashSet<_>>();
+    for _ in 0..nr_connections {
+        let pair = computers.choose_multiple(rng, 2).collect_vec();
+        if !connections.contains(&(pair[1], pair[0])) {
+            connections.insert((pair[0], pair[1]));
+        }
+    }
+
+    let mut connections = connections.into_iter().collect_vec();
+    connections.sort();
+    connections.shuffle(rng);
+    connections
+        .iter()
+        .map(|(a, b)| format!("{a}-{b}\n"))
+        .join("")
+}
+
+/// Ripple-carry adder with its gate outputs swapped in pairs, and the wires that were swapped.
+pub struct Netlist {
+    pub input: String,
+    /// Sorted, joined by commas, as part 2 of day 24 expects them.
+    pub swapped: String,
+}
+
+/// Adder of two `nr_bits` numbers, with `nr_swaps` pairs of gate outputs swapped.
+///
+/// Every swap breaks the adder at a different bit, in one of the ways the puzzle does:
+/// a sum bit swapped with the carry or with an intermediate AND, or both gates taking the inputs
+/// of a bit swapped with each other. Neither the first nor the last bit gets broken, so it takes
+/// `nr_swaps + 2` bits at least.
+pub fn day_24(rng: &mut impl Rng, nr_bits: usize, nr_swaps: usize) -> Netlist {
+    assert!(
+        nr_bits >= 2 && nr_swaps <= nr_bits - 2,
+        "not enough bits to swap"
+    );
+
+    let mut names = HashSet::new();
+    let mut name = |rng: &mut _| loop {
+        let name = (0..3)
+            .map(|_| (b'a' + Rng::random_range(rng, 0..23)) as char)
+            .collect::<String>();
+        if names.insert(name.clone()) {
+            return name;
+        }
+    };
+    let wire = |prefix: char, bit: usize| format!("{prefix}{bit:0>2}");
+
+    // Per bit: the XOR and AND of both inputs, the AND of the carry, and the carry out
+    let mut gates: Vec<(String, &str, String, String)> = vec![];
+    let mut carry = name(rng);
+    gates.push((wire('x', 0), "XOR", wire('y', 0), wire('z', 0)));
+    gates.push((wire('x', 0), "AND", wire('y', 0), carry.clone()));
+    for bit in 1..nr_bits {
+        let sum = name(rng);
+        let and = name(rng);
+        let carry_and = name(rng);
+        let carry_out = if bit == nr_bits - 1 {
+            wire('z', nr_bits)
+        } else {
+            name(rng)
+        };
+
+        gates.push((wire('x', bit), "XOR", wire('y', bit), sum.clone()));
+        gates.push((wire('x', bit), "AND", wire('y', bit), and.clone()));
+        gates.push((sum.clone(), "XOR", carry.clone(), wire('z', bit)));
+        gates.push((sum, "AND", carry, carry_and.clone()));
+        gates.push((and, "OR", carry_and, carry_out.clone()));
+        carry = carry_out;
+    }
+
+    let mut bits = (1..nr_bits - 1).collect_vec();
+    bits.shuffle(rng);
+    let mut swapped = vec![];
+    for &bit in bits.iter().take(nr_swaps) {
+        // Gates of bit `bit` in the order they were added above
+        let first = 2 + 5 * (bit - 1);
+        let (a, b) = *[(2, 4), (2, 3), (0, 1)].choose(rng).unwrap();
+        let (a, b) = (first + a, first + b);
+
+        let out_a = gates[a].3.clone();
+        gates[a].3 = std::mem::replace(&mut gates[b].3, out_a);
+        swapped.extend([gates[a].3.clone(), gates[b].3.clone()]);
+    }
+
+    let wires = ['x', 'y']
+        .iter()
+        .flat_map(|&prefix| (0..nr_bits).map(move |bit| wire(prefix, bit)))
+        .map(|wire| format!("{}: {}", wire, rng.random_range(0..2)))
+        .join("\n");
+    gates.shuffle(rng);
+    let gates = gates
+        .iter()
+        .map(|(in_1, gate, in_2, out)| match rng.random_bool(0.5) {
+            true => format!("{in_1} {gate} {in_2} -> {out}"),
+            false => format!("{in_2} {gate} {in_1} -> {out}"),
+        })
+        .join("\n");
+
+    Netlist {
+        input: format!("{wires}\n\n{gates}\n"),
+        swapped: swapped.iter().sorted().join(","),
+    }
+}
+
+/// `nr_locks` locks and `nr_keys` keys, in the puzzle's 5 by 7 schematics, with random pin heights.
+pub fn day_25(rng: &mut impl Rng, nr_locks: usize, nr_keys: usize) -> String {
+    (0..nr_locks + nr_keys)
+        .map(|idx| {
+            let pins = [(); 5].map(|_| rng.random_range(0..=5));
+            let mut schematic = (0..7)
+                .map(|row| {
+                    pins.iter()
+                        .map(|&pin| if row <= pin { '#' } else { '.' })
+                        .collect_vec()
+                })
+                .collect_vec();
+            schematic[0] = vec!['#'; 5];
+            if idx >= nr_locks {
+                schematic.reverse();
+            }
+
+            render(&schematic)
+        })
+        .collect_vec()
+        .join("\n")
+}
+
+/// Perfect maze carved out of a grid of walls, with open tiles on all odd coordinates.
+fn maze(rng: &mut impl Rng, width: usize, height: usize) -> Vec<Vec<char>> {
+    assert!(
+        width % 2 == 1 && height % 2 == 1 && width >= 5 && height >= 5,
+        "maze needs odd dimensions of at least 5"
+    );
+
+    let mut maze = vec![vec!['#'; width]; height];
+    let mut stack = vec![(1, 1)];
+    maze[1][1] = '.';
+
+    while let Some(&(x, y)) = stack.last() {
+        let unvisited = [(2i64, 0i64), (-2, 0), (0, 2), (0, -2)]
+            .iter()
+            .map(|(dx, dy)| (x as i64 + dx, y as i64 + dy))
+            .filter(|&(nx, ny)| {
+                (1..width as i64 - 1).contains(&nx)
+                    && (1..height as i64 - 1).contains(&ny)
+                    && maze[ny as usize][nx as usize] == '#'
+            })
+            .map(|(nx, ny)| (nx as usize, ny as usize))
+            .collect_vec();
+
+        match unvisited.choose(rng) {
+            Some(&(nx, ny)) => {
+                maze[(y + ny) / 2][(x + nx) / 2] = '.';
+                maze[ny][nx] = '.';
+                stack.push((nx, ny));
+            }
+            None => {
+                stack.pop();
+            }
+        }
+    }
+
+    maze
+}
+
+type Tile = (usize, usize);
+
+/// Every open tile reachable from `start`, with its distance and the tile it was reached from.
+fn bfs(grid: &[Vec<char>], start: Tile) -> Vec<(Tile, usize, Option<Tile>)> {
+    let mut seen = vec![vec![false; grid[0].len()]; grid.len()];
+    let mut reached = vec![];
+    let mut queue = VecDeque::from([(start, 0, None)]);
+    seen[start.1][start.0] = true;
+
+    while let Some((tile, distance, from)) = queue.pop_front() {
+        reached.push((tile, distance, from));
+
+        neighbours(tile.0, tile.1, grid[0].len(), grid.len())
+            .filter(|&(x, y)| grid[y][x] != '#')
+            .for_each(|(x, y)| {
+                if !seen[y][x] {
+                    seen[y][x] = true;
+                    queue.push_back(((x, y), distance + 1, Some(tile)));
+                }
+            });
+    }
+
+    reached
+}
+
+fn neighbours(x: usize, y: usize, width: usize, height: usize) -> impl Iterator<Item = Tile> {
+    [(1i64, 0i64), (-1, 0), (0, 1), (0, -1)]
+        .into_iter()
+        .map(move |(dx, dy)| (x as i64 + dx, y as i64 + dy))
+        .filter(move |&(nx, ny)| {
+            (0..width as i64).contains(&nx) && (0..height as i64).contains(&ny)
+        })
+        .map(|(nx, ny)| (nx as usize, ny as usize))
+}
+
+fn render(grid: &[Vec<char>]) -> String {
+    grid.iter()
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solutions::y2024::day_09::{DiskMap, Strategy};
+    use crate::solutions::y2024::day_20::Track;
+    use crate::solutions::y2024::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use std::str::FromStr;
+
+    #[test]
+    fn generated_grids() {
+        let mut rng = StdRng::seed_from_u64(2024);
+
+        for _ in 0..20 {
+            let lab = day_06(&mut rng, 30, 20, 0.1);
+            assert!(day_06::solve_1(&lab.lines().collect_vec()) > 0);
+
+            let map = day_10(&mut rng, 20, 20, 5);
+            let map = map.lines().collect_vec();
+            assert!(day_10::solve_1(&map) >= 1);
+            assert!(day_10::solve_1(&map) <= day_10::solve_2(&map));
+
+            let garden = day_12(&mut rng, 25, 15, 4, 0.6);
+            let garden = garden.lines().collect_vec();
+            assert!(day_12::solve_2(&garden) <= day_12::solve_1(&garden));
+
+            let maze = day_16(&mut rng, 21, 15, 0.1);
+            let maze = maze.lines().collect_vec();
+            assert!(day_16::solve_1(&maze) > 0);
+            assert!(day_16::solve_2(&maze) >= 2);
+
+            let track = day_20(&mut rng, 21, 21);
+            let path = track.matches(['.', 'S', 'E']).count() as i32;
+            assert_eq!(
+                path - 1,
+                Track::new(&track.lines().collect_vec()).best_time()
+            );
+        }
+    }
+
+    #[test]
+    fn generated_lists() {
+        let mut rng = StdRng::seed_from_u64(2024);
+        let room = Room {
+            width: 11,
+            height: 7,
+        };
+
+        for _ in 0..20 {
+            let disk = day_09(&mut rng, 50);
+            let disk = disk.trim();
+            assert_eq!(99, disk.len());
+            assert_eq!(
+                DiskMap::new(disk).compact(Strategy::BlockWise).checksum,
+                day_09::solve_1(disk)
+            );
+            assert_eq!(
+                DiskMap::new(disk).compact(Strategy::FirstFit).checksum,
+                day_09::solve_2(disk)
+            );
+
+            let robots = day_14(&mut rng, &room, 12);
+            assert_eq!(12, robots.lines().count());
+            assert_eq!(
+                day_14::solve_1(&robots.lines().collect_vec(), &room),
+                day_14::solve_1_from(robots.as_bytes(), &room).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn generated_line_inputs() {
+        let mut rng = StdRng::seed_from_u64(2024);
+
+        for _ in 0..20 {
+            let locations = day_01(&mut rng, 100, 1_000);
+            assert_eq!(
+                day_01::solve_1(&locations),
+                day_01::solve_1_from(locations.as_bytes()).unwrap()
+            );
+            assert_eq!(
+                day_01::solve_2(&locations),
+                day_01::solve_2_from(locations.as_bytes()).unwrap()
+            );
+            assert!(day_01::solve_2(&locations) > 0);
+
+            let reports = day_02(&mut rng, 100);
+            let safe = day_02::solve_1(&reports.lines().collect_vec());
+            assert!(safe > 0 && safe <= day_02::solve_2(&reports.lines().collect_vec()));
+            assert_eq!(safe, day_02::solve_1_from(reports.as_bytes()).unwrap());
+            assert_eq!(
+                day_02::solve_2(&reports.lines().collect_vec()),
+                day_02::solve_2_from(reports.as_bytes()).unwrap()
+            );
+
+            let calibrations = day_07(&mut rng, 50, 6);
+            let calibrations_lines = calibrations.lines().collect_vec();
+            let total = day_07::solve_1(&calibrations_lines);
+            assert!(total > 0 && total <= day_07::solve_2(&calibrations_lines));
+            assert_eq!(
+                total,
+                day_07::solve_1_from(calibrations.as_bytes()).unwrap()
+            );
+            assert_eq!(
+                day_07::solve_2(&calibrations_lines),
+                day_07::solve_2_from(calibrations.as_bytes()).unwrap()
+            );
+        }
+
+        let secrets = day_22(&mut rng, 50);
+        let secrets_lines = secrets.lines().collect_vec();
+        assert_eq!(
+            day_22::solve_1(&secrets_lines),
+            day_22::solve_1_from(secrets.as_bytes()).unwrap()
+        );
+        assert_eq!(
+            day_22::solve_2(&secrets_lines),
+            day_22::solve_2_from(secrets.as_bytes()).unwrap()
+        );
+    }
+
+    #[test]
+    fn generated_puzzles() {
+        let mut rng = StdRng::seed_from_u64(2024);
+
+        for _ in 0..10 {
+            let memory = day_03(&mut rng, 50);
+            assert!(day_03::solve_2(&memory) <= day_03::solve_1(&memory));
+            assert!(day_03::solve_1(&memory) > 0);
+
+            let word_search = day_04(&mut rng, 20, 20);
+            assert!(day_04::solve_1(&word_search.lines().collect_vec()) > 0);
+
+            let manual = day_05(&mut rng, 30, 20);
+            assert!(day_05::solve_1(manual.trim()) > 0);
+            assert!(day_05::solve_2(manual.trim()) > 0);
+
+            let city = day_08(&mut rng, 30, 30, 5, 30);
+            let city = city.lines().collect_vec();
+            assert!(day_08::solve_1(&city) <= day_08::solve_2(&city));
+
+            let machines = day_13(&mut rng, 20);
+            assert!(day_13::solve_1(machines.trim()) > 0);
+
+            let warehouse = day_15(&mut rng, 20, 15, 0.2, 500);
+            let boxes = warehouse.matches('O').count();
+            assert!(day_15::solve_1(warehouse.trim()) >= 100 * boxes);
+            assert!(day_15::solve_2(warehouse.trim()) >= 100 * boxes);
+
+            let bytes = day_18(&mut rng, 12);
+            let bytes = bytes.lines().collect_vec();
+            assert_eq!(167, bytes.len());
+            assert_eq!(24, day_18::solve_1(&bytes, 0, 12));
+            assert!(bytes.contains(&day_18::solve_2(&bytes, 12).as_str()));
+
+            let towels = day_19(&mut rng, 10, 20);
+            let towels = towels.lines().collect_vec();
+            let possible = day_19::solve_1(&towels);
+            assert!(possible >= 10 && possible as u64 <= day_19::solve_2(&towels));
+
+            let network = day_23(&mut rng, 200, 300, 8);
+            let network = network.lines().collect_vec();
+            assert!(day_23::solve_2(&network).split(',').count() >= 8);
+
+            let schematics = day_25(&mut rng, 15, 20);
+            assert_eq!(35, schematics.split("\n\n").count());
+            assert_eq!(
+                day_25::Schematics::from_str(schematics.trim())
+                    .unwrap()
+                    .matching_pairs()
+                    .len(),
+                day_25::solve_1(schematics.trim())
+            );
+        }
+    }
+
+    #[test]
+    fn generated_netlists() {
+        let mut rng = StdRng::seed_from_u64(2024);
+
+        let Netlist { input, swapped } = day_24(&mut rng, 10, 0);
+        assert!(swapped.is_empty());
+        let [x, y] = ['x', 'y'].map(|prefix| {
+            let bits = input
+                .lines()
+                .filter(|line| line.starts_with(prefix) && line.contains(':'))
+                .rev()
+                .map(|line| &line[5..])
+                .join("");
+            u64::from_str_radix(&bits, 2).unwrap()
+        });
+        assert_eq!(x + y, day_24::solve_1(input.trim()));
+
+        for nr_bits in [8, 16, 45] {
+            let Netlist { input, swapped } = day_24(&mut rng, nr_bits, 4);
+            assert_eq!(swapped, day_24::solve_2(input.trim()));
+        }
+    }
+}
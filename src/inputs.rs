@@ -0,0 +1,241 @@
+use crate::client;
+use crate::client::{ClientError, HttpClient, RateLimited, UreqClient};
+use lazy_static::lazy_static;
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Duration;
+use std::{env, error, fs, io};
+
+/// Environment variable pointing to the directory inputs are cached in,
+/// defaults to `inputs/` at the root of the repository.
+pub const CACHE_DIR_VAR: &str = "AOC_INPUTS_DIR";
+
+/// Advent of Code asks to keep automated requests down, one every few seconds is plenty.
+const REQUEST_INTERVAL: Duration = Duration::from_secs(3);
+
+lazy_static! {
+    static ref INPUTS: Inputs = Inputs::from_env();
+}
+
+/// Input of a day, from the cache or else downloaded, `None` if it can not be had.
+///
+/// Meant for tests needing personal inputs, which are not part of the repository,
+/// these can then skip themselves instead of failing on a fresh clone.
+pub fn load(year: u16, day: u8) -> Option<String> {
+    INPUTS
+        .get(year, day)
+        .inspect_err(|e| eprintln!("skipping, no input for {year} day {day:0>2}: {e}"))
+        .ok()
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    /// Not cached, and without a session the input can not be downloaded.
+    MissingSession,
+    Client(ClientError),
+    /// Advent of Code answered, but not with the input (e.g. not logged in, not unlocked yet).
+    Status(u16),
+    Io(io::Error),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::MissingSession => {
+                write!(f, "not cached and {} is not set", client::SESSION_VAR)
+            }
+            InputError::Client(e) => write!(f, "{e}"),
+            InputError::Status(status) => write!(f, "download answered with status {status}"),
+            InputError::Io(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl error::Error for InputError {}
+
+/// Inputs cached on disk as `<cache>/yYYYY/day_XX.txt`, downloading the ones missing.
+pub struct Inputs<C = RateLimited<UreqClient>> {
+    cache: PathBuf,
+    base_url: String,
+    session: Option<String>,
+    client: C,
+    /// Held while downloading, so concurrent callers don't fetch the same input twice.
+    downloading: Mutex<()>,
+}
+
+impl Inputs {
+    pub fn from_env() -> Self {
+        Self::new(
+            cache_dir(),
+            client::BASE_URL,
+            client::session_from_env(client::SESSION_VAR),
+            RateLimited::new(UreqClient::new(), REQUEST_INTERVAL),
+        )
+    }
+
+    /// Inputs of another user, cached in a directory of their own within the cache,
+    /// downloaded with the session in `AOC_SESSION_<NAME>`.
+    pub fn for_user(name: &str) -> Self {
+        let session_var = format!("{}_{}", client::SESSION_VAR, name.to_uppercase());
+
+        Self::new(
+            cache_dir().join(name),
+            client::BASE_URL,
+            client::session_from_env(&session_var),
+            RateLimited::new(UreqClient::new(), REQUEST_INTERVAL),
+        )
+    }
+}
+
+fn cache_dir() -> PathBuf {
+    env::var(CACHE_DIR_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| Path::new(env!("CARGO_MANIFEST_DIR")).join("inputs"))
+}
+
+impl<C: HttpClient> Inputs<C> {
+    pub fn new(
+        cache: impl Into<PathBuf>,
+        base_url: &str,
+        session: Option<String>,
+        client: C,
+    ) -> Self {
+        Self {
+            cache: cache.into(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+            client,
+            downloading: Mutex::new(()),
+        }
+    }
+
+    pub fn cache(&self) -> &Path {
+        &self.cache
+    }
+
+    pub fn session(&self) -> Option<&str> {
+        self.session.as_deref()
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.cache
+            .join(format!("y{year}"))
+            .join(format!("day_{day:0>2}.txt"))
+    }
+
+    pub fn cached(&self, year: u16, day: u8) -> Option<String> {
+        fs::read_to_string(self.path(year, day)).ok()
+    }
+
+    pub fn get(&self, year: u16, day: u8) -> Result<String, InputError> {
+        if let Some(input) = self.cached(year, day) {
+            return Ok(input);
+        }
+
+        let _downloading = self.downloading.lock().unwrap();
+        // Another caller could have finished downloading it while this one was waiting
+        if let Some(input) = self.cached(year, day) {
+            return Ok(input);
+        }
+
+        let input = self.download(year, day)?;
+        self.store(year, day, &input).map_err(InputError::Io)?;
+
+        Ok(input)
+    }
+
+    fn download(&self, year: u16, day: u8) -> Result<String, InputError> {
+        let session = self.session.as_ref().ok_or(InputError::MissingSession)?;
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+
+        let response = self.client.get(&url, session).map_err(InputError::Client)?;
+        if !response.is_success() {
+            return Err(InputError::Status(response.status));
+        }
+
+        Ok(response.body)
+    }
+
+    /// Writes through a temporary file, a half-written input is never mistaken for a cached one.
+    fn store(&self, year: u16, day: u8, input: &str) -> io::Result<()> {
+        let path = self.path(year, day);
+        let partial = path.with_extension("part");
+
+        fs::create_dir_all(path.parent().unwrap())?;
+        fs::write(&partial, input)?;
+        fs::rename(partial, path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::mock::MockServer;
+    use std::process;
+
+    fn cache_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-inputs-{}-{}", process::id(), name));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn inputs_downloaded_once() {
+        let server = MockServer::start(|request| match request.path.as_str() {
+            "/2024/day/1/input" => (200, "3   4\n4   3\n".to_string()),
+            _ => (404, String::new()),
+        });
+        let dir = cache_dir("download");
+        let inputs = Inputs::new(
+            &dir,
+            &server.url(""),
+            Some("abc".to_string()),
+            UreqClient::new(),
+        );
+
+        assert!(inputs.cached(2024, 1).is_none());
+        assert_eq!("3   4\n4   3\n", inputs.get(2024, 1).unwrap());
+        assert_eq!("3   4\n4   3\n", inputs.get(2024, 1).unwrap());
+        assert_eq!(
+            "3   4\n4   3\n",
+            fs::read_to_string(dir.join("y2024").join("day_01.txt")).unwrap()
+        );
+
+        let requests = server.requests();
+        assert_eq!(1, requests.len());
+        assert_eq!(Some("session=abc"), requests[0].header("cookie"));
+    }
+
+    #[test]
+    fn inputs_not_cached_on_failure() {
+        let server = MockServer::start(|_| (404, "not unlocked yet".to_string()));
+        let dir = cache_dir("failure");
+        let inputs = Inputs::new(
+            &dir,
+            &server.url(""),
+            Some("abc".to_string()),
+            UreqClient::new(),
+        );
+
+        assert!(matches!(inputs.get(2024, 25), Err(InputError::Status(404))));
+        assert!(inputs.cached(2024, 25).is_none());
+    }
+
+    #[test]
+    fn inputs_without_session() {
+        let server = MockServer::start(|_| (200, String::new()));
+        let dir = cache_dir("session");
+        let inputs = Inputs::new(&dir, &server.url(""), None, UreqClient::new());
+
+        assert!(matches!(
+            inputs.get(2024, 1),
+            Err(InputError::MissingSession)
+        ));
+
+        fs::create_dir_all(dir.join("y2024")).unwrap();
+        fs::write(dir.join("y2024").join("day_02.txt"), "7 6 4 2 1\n").unwrap();
+        assert_eq!("7 6 4 2 1\n", inputs.get(2024, 2).unwrap());
+        assert!(server.requests().is_empty());
+    }
+}
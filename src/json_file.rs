@@ -0,0 +1,89 @@
+//! Answers, guesses and timing baselines are all kept as pretty-printed JSON files,
+//! a missing file counting as an empty one.
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+use std::{error, fs, io};
+
+#[derive(Debug)]
+pub enum JsonFileError {
+    Io {
+        path: PathBuf,
+        error: io::Error,
+    },
+    Json {
+        path: PathBuf,
+        error: serde_json::Error,
+    },
+}
+
+impl Display for JsonFileError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JsonFileError::Io { path, error } => write!(f, "{}: {error}", path.display()),
+            JsonFileError::Json { path, error } => {
+                write!(f, "invalid JSON in {}: {error}", path.display())
+            }
+        }
+    }
+}
+
+impl error::Error for JsonFileError {}
+
+impl JsonFileError {
+    pub fn io(path: &Path, error: io::Error) -> Self {
+        JsonFileError::Io {
+            path: path.to_path_buf(),
+            error,
+        }
+    }
+
+    fn json(path: &Path, error: serde_json::Error) -> Self {
+        JsonFileError::Json {
+            path: path.to_path_buf(),
+            error,
+        }
+    }
+}
+
+/// Contents of the file, the default if there is no such file.
+pub fn load<T: DeserializeOwned + Default>(path: &Path) -> Result<T, JsonFileError> {
+    match fs::read_to_string(path) {
+        Ok(json) => serde_json::from_str(&json).map_err(|e| JsonFileError::json(path, e)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(T::default()),
+        Err(e) => Err(JsonFileError::io(path, e)),
+    }
+}
+
+/// Writes the file, creating any directories leading up to it.
+pub fn save<T: Serialize>(value: &T, path: &Path) -> Result<(), JsonFileError> {
+    let json = serde_json::to_string_pretty(value).map_err(|e| JsonFileError::json(path, e))?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| JsonFileError::io(parent, e))?;
+    }
+    fs::write(path, json + "\n").map_err(|e| JsonFileError::io(path, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+    use std::{env, process};
+
+    #[test]
+    fn json_file_errors() {
+        let path = env::temp_dir().join(format!("aoc-json-file-{}.json", process::id()));
+        fs::write(&path, "{ not json").unwrap();
+
+        let error = load::<BTreeMap<String, u64>>(&path).unwrap_err();
+        assert!(matches!(error, JsonFileError::Json { .. }));
+        assert!(error
+            .to_string()
+            .starts_with(&format!("invalid JSON in {}: ", path.display())));
+
+        fs::remove_file(&path).unwrap();
+        assert!(load::<BTreeMap<String, u64>>(&path).unwrap().is_empty());
+    }
+}
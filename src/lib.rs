@@ -1,2 +1,22 @@
+#[cfg(not(target_arch = "wasm32"))]
+pub mod answers;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod client;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod generators;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod inputs;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod json_file;
+pub mod parallel;
+#[cfg(test)]
+mod reference;
+pub mod registry;
 pub mod solutions;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod submit;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod timing;
 mod util;
+#[cfg(feature = "wasm")]
+pub mod wasm;
@@ -0,0 +1,196 @@
+//! How days spread independent work, such as lines of input, over threads.
+//!
+//! The setting holds for the whole library, days going through [`map`] and [`fold`] rather than
+//! using Rayon directly, so all of them can be made serial or given a pool of a fixed size.
+//! WebAssembly has no threads, there everything runs serially whatever the setting.
+
+use lazy_static::lazy_static;
+#[cfg(not(target_arch = "wasm32"))]
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+#[cfg(not(target_arch = "wasm32"))]
+use rayon::{ThreadPool, ThreadPoolBuilder};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+#[cfg(not(target_arch = "wasm32"))]
+use std::sync::Arc;
+use std::sync::RwLock;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub type PoolError = rayon::ThreadPoolBuildError;
+/// Without threads no pool ever gets built.
+#[cfg(target_arch = "wasm32")]
+pub type PoolError = std::convert::Infallible;
+
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub enum Parallelism {
+    /// Everything on the calling thread, in order, for deterministic profiling.
+    Serial,
+    /// Rayon's global pool, with a thread per CPU.
+    #[default]
+    Global,
+    /// A pool of its own with this many threads.
+    Threads(usize),
+}
+
+impl FromStr for Parallelism {
+    type Err = &'static str;
+
+    /// Parses `serial`, `global`, or a number of threads.
+    fn from_str(parallelism: &str) -> Result<Self, Self::Err> {
+        match parallelism {
+            "serial" => Ok(Self::Serial),
+            "global" => Ok(Self::Global),
+            threads => threads
+                .parse()
+                .ok()
+                .filter(|&threads| threads > 0)
+                .map(Self::Threads)
+                .ok_or("parallelism should be serial, global or a number of threads"),
+        }
+    }
+}
+
+impl Display for Parallelism {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Serial => write!(f, "serial"),
+            Self::Global => write!(f, "global"),
+            Self::Threads(threads) => write!(f, "{threads}"),
+        }
+    }
+}
+
+#[derive(Default)]
+struct Config {
+    parallelism: Parallelism,
+    #[cfg(not(target_arch = "wasm32"))]
+    pool: Option<Arc<ThreadPool>>,
+}
+
+lazy_static! {
+    static ref CONFIG: RwLock<Config> = RwLock::new(Config::default());
+}
+
+/// Applies from then on to every day, building a new pool if it needs one of its own.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn set(parallelism: Parallelism) -> Result<(), PoolError> {
+    let pool = match parallelism {
+        Parallelism::Threads(threads) => Some(Arc::new(
+            ThreadPoolBuilder::new().num_threads(threads).build()?,
+        )),
+        _ => None,
+    };
+
+    *CONFIG.write().unwrap() = Config { parallelism, pool };
+    Ok(())
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn set(parallelism: Parallelism) -> Result<(), PoolError> {
+    *CONFIG.write().unwrap() = Config { parallelism };
+    Ok(())
+}
+
+pub fn get() -> Parallelism {
+    CONFIG.read().unwrap().parallelism
+}
+
+/// Maps every item, keeping them in order.
+pub fn map<T, R>(items: &[T], f: impl Fn(&T) -> R + Sync + Send) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+{
+    #[cfg(not(target_arch = "wasm32"))]
+    if let Some(threads) = threads() {
+        return threads.install(|| items.par_iter().map(&f).collect());
+    }
+
+    items.iter().map(&f).collect()
+}
+
+/// Folds the items into accumulators starting from `identity`, one per thread,
+/// then combines those. Items are folded in order when serial, in any order otherwise.
+pub fn fold<T, A>(
+    items: &[T],
+    identity: impl Fn() -> A + Sync + Send,
+    fold: impl Fn(A, &T) -> A + Sync + Send,
+    reduce: impl Fn(A, A) -> A + Sync + Send,
+) -> A
+where
+    T: Sync,
+    A: Send,
+{
+    #[cfg(not(target_arch = "wasm32"))]
+    if let Some(threads) = threads() {
+        return threads.install(|| {
+            items
+                .par_iter()
+                .fold(&identity, &fold)
+                .reduce(&identity, &reduce)
+        });
+    }
+    // A single accumulator has nothing to be combined with
+    #[cfg(target_arch = "wasm32")]
+    let _ = reduce;
+
+    items.iter().fold(identity(), &fold)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+enum Threads {
+    Global,
+    Pool(Arc<ThreadPool>),
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Threads {
+    fn install<R: Send>(&self, f: impl FnOnce() -> R + Send) -> R {
+        match self {
+            Threads::Global => f(),
+            Threads::Pool(pool) => pool.install(f),
+        }
+    }
+}
+
+/// Threads to spread work over, none when serial.
+#[cfg(not(target_arch = "wasm32"))]
+fn threads() -> Option<Threads> {
+    let config = CONFIG.read().unwrap();
+
+    match (config.parallelism, &config.pool) {
+        (Parallelism::Serial, _) => None,
+        (_, Some(pool)) => Some(Threads::Pool(pool.clone())),
+        (_, None) => Some(Threads::Global),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parallel_parse() {
+        assert_eq!(Ok(Parallelism::Serial), "serial".parse());
+        assert_eq!(Ok(Parallelism::Global), "global".parse());
+        assert_eq!(Ok(Parallelism::Threads(4)), "4".parse());
+        assert!("0".parse::<Parallelism>().is_err());
+        assert!("all".parse::<Parallelism>().is_err());
+        assert_eq!("serial", Parallelism::Serial.to_string());
+    }
+
+    #[test]
+    fn parallel_fold() {
+        let items = (1..=100).collect::<Vec<u64>>();
+        let sum = || fold(&items, || 0, |sum, n| sum + n, |a, b| a + b);
+
+        // Other tests run days concurrently, so the setting gets put back afterwards
+        let previous = get();
+        let results = [Parallelism::Serial, Parallelism::Threads(3), previous].map(|parallelism| {
+            set(parallelism).unwrap();
+            (sum(), map(&items, |n| n * 2)[99])
+        });
+
+        assert_eq!([(5_050, 200); 3], results);
+    }
+}
@@ -0,0 +1,306 @@
+//! Deliberately naive solutions, slow but simple enough to be obviously right, to compare
+//! optimised solutions with on small random inputs from [`generators`](crate::generators).
+
+use std::fmt::Debug;
+use std::panic;
+use std::panic::AssertUnwindSafe;
+
+pub mod day_09;
+pub mod day_11;
+pub mod day_17;
+pub mod day_20;
+pub mod day_21;
+
+/// Checks `agree` on every input, panicking with the smallest counter-example `shrink` can find
+/// from the first input on which it does not hold. Panics in `agree` count as disagreeing.
+pub fn differential<T: Debug>(
+    inputs: impl IntoIterator<Item = T>,
+    agree: impl Fn(&T) -> bool,
+    shrink: impl Fn(&T) -> Vec<T>,
+) {
+    let agrees =
+        |input: &T| panic::catch_unwind(AssertUnwindSafe(|| agree(input))).unwrap_or(false);
+
+    if let Some(input) = inputs.into_iter().find(|input| !agrees(input)) {
+        let counter_example = minimise(input, |input| !agrees(input), shrink);
+        panic!("solutions disagree on {counter_example:?}");
+    }
+}
+
+/// Shrinks a failing input for as long as any of its smaller versions keeps on failing.
+pub fn minimise<T>(mut input: T, fails: impl Fn(&T) -> bool, shrink: impl Fn(&T) -> Vec<T>) -> T {
+    while let Some(smaller) = shrink(&input).into_iter().find(&fails) {
+        input = smaller;
+    }
+
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{differential, minimise};
+    use crate::generators;
+    use crate::reference;
+    use crate::solutions::y2024::*;
+    use itertools::Itertools;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn reference_minimise() {
+        let shrink = |n: &u32| vec![n / 2, n - 1];
+
+        assert_eq!(10, minimise(1_000, |&n| n >= 10, shrink));
+        assert_eq!(7, minimise(7, |&n| n > 10, shrink));
+    }
+
+    #[test]
+    #[should_panic(expected = "solutions disagree on 16")]
+    fn reference_differential() {
+        differential(1..100u32, |&n| n < 16 || n % 2 == 1, |&n| vec![n / 2]);
+    }
+
+    #[test]
+    fn reference_day_09() {
+        let mut rng = StdRng::seed_from_u64(2024);
+        let disks = (0..200).map(|_| {
+            let nr_files = rng.random_range(1..20);
+            generators::day_09(&mut rng, nr_files).trim().to_string()
+        });
+
+        differential(
+            disks,
+            |disk| {
+                reference::day_09::solve_1(disk) == day_09::solve_1(disk)
+                    && reference::day_09::solve_2(disk) == day_09::solve_2(disk)
+            },
+            |disk| {
+                // Drop a file with the gap behind it, or shrink a single file or gap
+                let digits = disk.chars().collect_vec();
+                let dropped = (0..digits.len() / 2).map(|file| {
+                    let mut digits = digits.clone();
+                    digits.drain(file * 2..file * 2 + 2);
+                    digits
+                });
+                let shrunk = (0..digits.len())
+                    .filter(|&idx| digits[idx] > if idx % 2 == 0 { '1' } else { '0' })
+                    .map(|idx| {
+                        let mut digits = digits.clone();
+                        digits[idx] = (digits[idx] as u8 - 1) as char;
+                        digits
+                    });
+
+                dropped.chain(shrunk).map(String::from_iter).collect()
+            },
+        );
+    }
+
+    #[test]
+    fn reference_day_11() {
+        let mut rng = StdRng::seed_from_u64(2024);
+        let stones = (0..50).map(|_| {
+            let nr_stones = rng.random_range(1..4);
+            generators::day_11(&mut rng, nr_stones, 4)
+                .trim()
+                .to_string()
+        });
+
+        differential(
+            stones,
+            |stones| {
+                let mut engine = day_11::Engine::<u64>::new(stones, day_11::Rules::default());
+                let totals = engine.totals(20);
+
+                (1..=20).all(|blinks| {
+                    totals[blinks as usize] == reference::day_11::blink(stones, blinks).len() as u64
+                })
+            },
+            |stones| {
+                let stones = stones.split(' ').collect_vec();
+                (0..stones.len())
+                    .flat_map(|idx| {
+                        let without = (stones.len() > 1).then(|| {
+                            let mut without = stones.clone();
+                            without.remove(idx);
+                            without.join(" ")
+                        });
+                        let halved = stones[idx].parse::<u64>().unwrap() / 2;
+                        let halved = (halved.to_string() != stones[idx]).then(|| {
+                            let mut halved_stones =
+                                stones.iter().map(|s| s.to_string()).collect_vec();
+                            halved_stones[idx] = halved.to_string();
+                            halved_stones.join(" ")
+                        });
+                        [without, halved]
+                    })
+                    .flatten()
+                    .collect()
+            },
+        );
+
+        let stones = "125 17";
+        assert_eq!(reference::day_11::solve_1(stones), day_11::solve_1(stones));
+    }
+
+    #[test]
+    fn reference_day_17() {
+        let mut rng = StdRng::seed_from_u64(2024);
+        let programs = (0..20)
+            .map(|_| {
+                let nr_instructions = rng.random_range(3..=4);
+                generators::day_17(&mut rng, nr_instructions)
+            })
+            // Programs this short come in few shapes, and searching a single one takes a while
+            .unique_by(|program| program.lines().last().unwrap().to_string())
+            .filter_map(|program| {
+                let lines = program.lines().collect_vec();
+                // A quine outputs as many numbers as the program holds, one per 3 bits of A
+                let limit = 8u64.pow(lines[4][9..].split(',').count() as u32);
+                let a = reference::day_17::solve_2(&lines, limit)?;
+                Some((program, a))
+            })
+            .collect_vec();
+        assert!(!programs.is_empty());
+
+        differential(
+            programs,
+            |(program, a)| day_17::solve_2(&program.lines().collect_vec()) == *a,
+            shrink_program,
+        );
+
+        let sample = [
+            "Register A: 2024",
+            "Register B: 0",
+            "Register C: 0",
+            "",
+            "Program: 0,3,5,4,3,0",
+        ];
+        assert_eq!(
+            Some(117_440),
+            reference::day_17::solve_2(&sample, 8u64.pow(6))
+        );
+    }
+
+    #[test]
+    fn reference_day_20() {
+        let mut rng = StdRng::seed_from_u64(2024);
+        let tracks = (0..20).map(|_| {
+            let size = 2 * rng.random_range(3..7) + 1;
+            let min_save = rng.random_range(1..20);
+            (generators::day_20(&mut rng, size, size), min_save)
+        });
+
+        differential(
+            tracks,
+            |(track, min_save)| {
+                let track = track.lines().collect_vec();
+                reference::day_20::solve_1(&track, *min_save) == day_20::solve_1(&track, *min_save)
+                    && reference::day_20::solve_2(&track, *min_save)
+                        == day_20::solve_2(&track, *min_save)
+            },
+            shrink_track,
+        );
+    }
+
+    #[test]
+    fn reference_day_21() {
+        let mut rng = StdRng::seed_from_u64(2024);
+        let codes = (0..50).map(|_| {
+            let nr_codes = rng.random_range(1..6);
+            generators::day_21(&mut rng, nr_codes)
+        });
+
+        differential(
+            codes,
+            |codes| {
+                let codes = codes.lines().collect_vec();
+                reference::day_21::solve_1(&codes) == day_21::solve_1(&codes)
+            },
+            |codes| {
+                let codes = codes.lines().collect_vec();
+                (0..codes.len())
+                    .filter(|_| codes.len() > 1)
+                    .map(|idx| {
+                        let mut codes = codes.clone();
+                        codes.remove(idx);
+                        codes.iter().map(|code| format!("{code}\n")).join("")
+                    })
+                    .collect()
+            },
+        );
+
+        let codes = ["029A", "980A", "179A", "456A", "379A"];
+        assert_eq!(126_384, reference::day_21::solve_1(&codes));
+    }
+
+    #[test]
+    fn reference_shrinkers() {
+        let program = "Register A: 0\nRegister B: 0\nRegister C: 0\n\nProgram: 0,3,5,4,3,0\n";
+        let smallest = minimise((program.to_string(), 117_440), |_| true, shrink_program);
+        assert_eq!(program, smallest.0);
+
+        let program = "Register A: 0\nRegister B: 0\nRegister C: 0\n\nProgram: 1,0,0,3,5,4,3,0\n";
+        let smallest = minimise((program.to_string(), 0), |_| true, shrink_program);
+        assert_eq!("Program: 0,3,5,4,3,0", smallest.0.lines().last().unwrap());
+
+        let track = "#####\n#...#\n#S#E#\n#####\n";
+        let smallest = minimise((track.to_string(), 5), |_| true, shrink_track);
+        assert_eq!(("#####\n###S#\n###E#\n#####\n".to_string(), 1), smallest);
+    }
+
+    /// Programs without one of their instructions, jumps excepted, that still output themselves.
+    fn shrink_program((program, _): &(String, u64)) -> Vec<(String, u64)> {
+        let lines = program.lines().collect_vec();
+        let numbers = lines[4][9..].split(',').collect_vec();
+        let instructions = numbers.chunks(2).map(|i| i.join(",")).collect_vec();
+
+        (0..instructions.len())
+            .filter(|&idx| !instructions[idx].starts_with('3'))
+            .filter_map(|idx| {
+                let mut instructions = instructions.clone();
+                instructions.remove(idx);
+                // Without an output, or without shifting A, it could loop forever
+                if ['0', '5']
+                    .iter()
+                    .any(|&opcode| !instructions.iter().any(|i| i.starts_with(opcode)))
+                {
+                    return None;
+                }
+                let program = format!(
+                    "{}\n\nProgram: {}\n",
+                    lines[..3].join("\n"),
+                    instructions.join(",")
+                );
+                let limit = 8u64.pow(2 * instructions.len() as u32);
+                let a = reference::day_17::solve_2(&program.lines().collect_vec(), limit)?;
+                Some((program, a))
+            })
+            .collect()
+    }
+
+    /// Tracks cut short at either end, or asking for smaller savings.
+    fn shrink_track((track, min_save): &(String, i32)) -> Vec<(String, i32)> {
+        let shortened = ['S', 'E'].into_iter().filter_map(|end| {
+            let mut grid = track
+                .lines()
+                .map(|line| line.chars().collect_vec())
+                .collect_vec();
+            let (x, y) = (0..grid.len())
+                .cartesian_product(0..grid[0].len())
+                .map(|(y, x)| (x, y))
+                .find(|&(x, y)| grid[y][x] == end)?;
+            let (next_x, next_y) = [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+                .into_iter()
+                .find(|&(x, y)| grid[y][x] == '.')?;
+
+            grid[y][x] = '#';
+            grid[next_y][next_x] = end;
+            let track = grid.iter().map(|row| row.iter().collect::<String>() + "\n");
+            Some((track.collect(), *min_save))
+        });
+
+        shortened
+            .chain((*min_save > 1).then(|| (track.clone(), min_save - 1)))
+            .collect()
+    }
+}
@@ -0,0 +1,48 @@
+//! Compacts the disk one block, or one file, at a time on the full block layout.
+
+pub fn solve_1(disk: &str) -> u64 {
+    let mut blocks = blocks(disk);
+
+    while let Some(free) = blocks.iter().position(Option::is_none) {
+        let last = blocks.iter().rposition(Option::is_some).unwrap();
+        if last < free {
+            break;
+        }
+        blocks.swap(free, last);
+    }
+
+    checksum(&blocks)
+}
+
+pub fn solve_2(disk: &str) -> u64 {
+    let mut blocks = blocks(disk);
+    let max_id = blocks.iter().flatten().max().copied().unwrap_or_default();
+
+    for id in (0..=max_id).rev() {
+        let start = blocks.iter().position(|&block| block == Some(id)).unwrap();
+        let size = blocks.iter().filter(|&&block| block == Some(id)).count();
+
+        let gap = (0..start).find(|&gap| blocks[gap..gap + size].iter().all(Option::is_none));
+        if let Some(gap) = gap {
+            (0..size).for_each(|offset| blocks.swap(gap + offset, start + offset));
+        }
+    }
+
+    checksum(&blocks)
+}
+
+fn blocks(disk: &str) -> Vec<Option<u64>> {
+    disk.chars()
+        .map(|c| c.to_digit(10).unwrap() as usize)
+        .enumerate()
+        .flat_map(|(idx, size)| vec![(idx % 2 == 0).then_some(idx as u64 / 2); size])
+        .collect()
+}
+
+fn checksum(blocks: &[Option<u64>]) -> u64 {
+    blocks
+        .iter()
+        .enumerate()
+        .map(|(idx, block)| idx as u64 * block.unwrap_or_default())
+        .sum()
+}
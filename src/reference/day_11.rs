@@ -0,0 +1,32 @@
+//! Blinks at every single stone, keeping them all in a line.
+
+pub fn solve_1(stones: &str) -> u64 {
+    blink(stones, 25).len() as u64
+}
+
+/// The line of stones after blinking `times` times.
+pub fn blink(stones: &str, times: u32) -> Vec<u64> {
+    let mut stones = stones
+        .split_whitespace()
+        .map(|stone| stone.parse().unwrap())
+        .collect::<Vec<u64>>();
+
+    for _ in 0..times {
+        stones = stones
+            .iter()
+            .flat_map(|&stone| {
+                let digits = stone.to_string();
+                match stone {
+                    0 => vec![1],
+                    _ if digits.len() % 2 == 0 => {
+                        let (left, right) = digits.split_at(digits.len() / 2);
+                        vec![left.parse().unwrap(), right.parse().unwrap()]
+                    }
+                    _ => vec![stone * 2024],
+                }
+            })
+            .collect();
+    }
+
+    stones
+}
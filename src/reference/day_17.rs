@@ -0,0 +1,47 @@
+//! Tries every value of register A in turn, until the program outputs itself.
+
+/// Lowest value of register A below `limit` making the program output itself, if any.
+pub fn solve_2(program: &[&str], limit: u64) -> Option<u64> {
+    let [b, c] = [1, 2].map(|line| program[line][12..].parse().unwrap());
+    let instructions = program[4][9..]
+        .split(',')
+        .map(|instruction| instruction.parse().unwrap())
+        .collect::<Vec<u64>>();
+
+    (0..limit).find(|&a| outputs_itself(&instructions, [a, b, c]))
+}
+
+/// Runs the program, stopping as soon as it outputs anything else than itself.
+fn outputs_itself(program: &[u64], [mut a, mut b, mut c]: [u64; 3]) -> bool {
+    let mut output = 0;
+    let mut ip = 0;
+
+    while ip + 1 < program.len() {
+        let literal = program[ip + 1];
+        let combo = match literal {
+            4 => a,
+            5 => b,
+            6 => c,
+            _ => literal,
+        };
+
+        match program[ip] {
+            0 => a >>= combo,
+            1 => b ^= literal,
+            2 => b = combo % 8,
+            3 if a != 0 => {
+                ip = literal as usize;
+                continue;
+            }
+            3 => {}
+            4 => b ^= c,
+            5 if program.get(output) == Some(&(combo % 8)) => output += 1,
+            5 => return false,
+            6 => b = a >> combo,
+            _ => c = a >> combo,
+        }
+        ip += 2;
+    }
+
+    output == program.len()
+}
@@ -0,0 +1,61 @@
+//! Tries cheating between every pair of tiles on the track.
+
+pub fn solve_1(track: &[&str], min_save: i32) -> usize {
+    count_cheats(track, min_save, 2)
+}
+
+pub fn solve_2(track: &[&str], min_save: i32) -> usize {
+    count_cheats(track, min_save, 20)
+}
+
+fn count_cheats(track: &[&str], min_save: i32, max_cheat_dist: i32) -> usize {
+    let find = |tile| {
+        track
+            .iter()
+            .enumerate()
+            .find_map(|(y, row)| row.find(tile).map(|x| (x as i32, y as i32)))
+            .unwrap()
+    };
+    let from_start = distances(track, find('S'));
+    let to_end = distances(track, find('E'));
+    let best_time = from_start[&find('E')];
+
+    from_start
+        .iter()
+        .flat_map(|(start, time)| {
+            to_end
+                .iter()
+                .map(move |(end, left)| (start, time, end, left))
+        })
+        .filter(|(start, time, end, left)| {
+            let length = (start.0 - end.0).abs() + (start.1 - end.1).abs();
+            length <= max_cheat_dist && best_time - (*time + length + *left) >= min_save.max(1)
+        })
+        .count()
+}
+
+/// Distance of every tile on the track to `from`.
+fn distances(track: &[&str], from: (i32, i32)) -> std::collections::HashMap<(i32, i32), i32> {
+    let mut distances = std::collections::HashMap::from([(from, 0)]);
+    let mut frontier = vec![from];
+
+    while !frontier.is_empty() {
+        let mut next = vec![];
+        for (x, y) in frontier {
+            let distance = distances[&(x, y)];
+            for neighbour in [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)] {
+                let on_track = track
+                    .get(neighbour.1 as usize)
+                    .and_then(|row| row.as_bytes().get(neighbour.0 as usize))
+                    .is_some_and(|&tile| tile != b'#');
+                if on_track && !distances.contains_key(&neighbour) {
+                    distances.insert(neighbour, distance + 1);
+                    next.push(neighbour);
+                }
+            }
+        }
+        frontier = next;
+    }
+
+    distances
+}
@@ -0,0 +1,103 @@
+//! Presses every button on our own keypad, moving all robot arms along, until each code is typed.
+
+use std::collections::{HashSet, VecDeque};
+
+const NUMERIC: [&str; 4] = ["789", "456", "123", " 0A"];
+const DIRECTIONAL: [&str; 2] = [" ^A", "<v>"];
+
+pub fn solve_1(codes: &[&str]) -> u64 {
+    solve(codes, 2)
+}
+
+/// Sum of the complexities of the codes, with `nr_robots` robots on directional keypads.
+pub fn solve(codes: &[&str], nr_robots: usize) -> u64 {
+    codes
+        .iter()
+        .map(|code| presses(code, nr_robots) * code[..code.len() - 1].parse::<u64>().unwrap())
+        .sum()
+}
+
+/// Fewest presses typing the code, searching over the arms of every robot and how much is typed.
+fn presses(code: &str, nr_robots: usize) -> u64 {
+    let code = code.as_bytes();
+    // Arms over the directional keypads, the one moved by our own presses last
+    let start = (
+        vec![position(&DIRECTIONAL, b'A'); nr_robots],
+        position(&NUMERIC, b'A'),
+        0,
+    );
+    let mut seen = HashSet::from([start.clone()]);
+    let mut queue = VecDeque::from([(start, 0)]);
+
+    while let Some(((arms, numeric, typed), presses)) = queue.pop_front() {
+        if typed == code.len() {
+            return presses;
+        }
+
+        for button in b"^v<>A" {
+            if let Some(next) = press(*button, &arms, numeric, typed, code) {
+                if seen.insert(next.clone()) {
+                    queue.push_back((next, presses + 1));
+                }
+            }
+        }
+    }
+
+    unreachable!("every code can be typed")
+}
+
+type State = (Vec<(i32, i32)>, (i32, i32), usize);
+
+/// Presses a button on our own keypad, `None` if an arm panics over a gap or mistypes.
+fn press(
+    mut button: u8,
+    arms: &[(i32, i32)],
+    mut numeric: (i32, i32),
+    typed: usize,
+    code: &[u8],
+) -> Option<State> {
+    let mut arms = arms.to_vec();
+
+    for arm in arms.iter_mut().rev() {
+        if button != b'A' {
+            *arm = step(*arm, button, &DIRECTIONAL)?;
+            return Some((arms, numeric, typed));
+        }
+        button = at(&DIRECTIONAL, *arm);
+    }
+
+    if button != b'A' {
+        numeric = step(numeric, button, &NUMERIC)?;
+        return Some((arms, numeric, typed));
+    }
+
+    (at(&NUMERIC, numeric) == code[typed]).then_some((arms, numeric, typed + 1))
+}
+
+fn step((x, y): (i32, i32), direction: u8, keypad: &[&str]) -> Option<(i32, i32)> {
+    let (x, y) = match direction {
+        b'^' => (x, y - 1),
+        b'v' => (x, y + 1),
+        b'<' => (x - 1, y),
+        _ => (x + 1, y),
+    };
+    let on_keypad = (0..keypad.len() as i32).contains(&y) && (0..3).contains(&x);
+
+    (on_keypad && at(keypad, (x, y)) != b' ').then_some((x, y))
+}
+
+fn at(keypad: &[&str], (x, y): (i32, i32)) -> u8 {
+    keypad[y as usize].as_bytes()[x as usize]
+}
+
+fn position(keypad: &[&str], button: u8) -> (i32, i32) {
+    keypad
+        .iter()
+        .enumerate()
+        .find_map(|(y, row)| {
+            row.bytes()
+                .position(|b| b == button)
+                .map(|x| (x as i32, y as i32))
+        })
+        .unwrap()
+}
@@ -0,0 +1,293 @@
+use crate::solutions::y2024::day_14::Room;
+use crate::solutions::*;
+use std::hint::black_box;
+use std::str::FromStr;
+
+/// Solves a part from the raw input, the answer formatted the way it would be submitted.
+pub type Solver = fn(&str) -> String;
+
+/// Only parses the raw input into the model of a day, to time it apart from solving.
+pub type Parser = fn(&str);
+
+/// Every solved day, with the parameters its puzzle uses for the personal inputs.
+#[derive(Debug, Copy, Clone)]
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub part_1: Solver,
+    /// The last day only has a single puzzle.
+    pub part_2: Option<Solver>,
+    /// Days solving everything in one go have no separate parsing step.
+    pub parse: Option<Parser>,
+}
+
+impl Day {
+    pub const fn new(year: u16, day: u8, part_1: Solver, part_2: Option<Solver>) -> Self {
+        Self {
+            year,
+            day,
+            part_1,
+            part_2,
+            parse: None,
+        }
+    }
+
+    pub const fn with_parse(mut self, parse: Parser) -> Self {
+        self.parse = Some(parse);
+        self
+    }
+
+    pub fn solver(&self, part: u8) -> Option<Solver> {
+        match part {
+            1 => Some(self.part_1),
+            2 => self.part_2,
+            _ => None,
+        }
+    }
+}
+
+pub fn days() -> &'static [Day] {
+    DAYS
+}
+
+pub fn find(year: u16, day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.year == year && d.day == day)
+}
+
+fn lines(input: &str) -> Vec<&str> {
+    input.lines().collect()
+}
+
+fn room() -> Room {
+    Room {
+        width: 101,
+        height: 103,
+    }
+}
+
+const DAYS: &[Day] = &[
+    Day::new(
+        2024,
+        1,
+        |input| y2024::day_01::solve_1(input.trim()).to_string(),
+        Some(|input| y2024::day_01::solve_2(input.trim()).to_string()),
+    )
+    .with_parse(|input| {
+        black_box(y2024::day_01::Histogram::from_lines(input.trim().lines()));
+    }),
+    Day::new(
+        2024,
+        2,
+        |input| y2024::day_02::solve_1(&lines(input)).to_string(),
+        Some(|input| y2024::day_02::solve_2(&lines(input)).to_string()),
+    )
+    .with_parse(|input| {
+        input.lines().for_each(|report| {
+            black_box(y2024::day_02::Report::new(report));
+        })
+    }),
+    Day::new(
+        2024,
+        3,
+        |input| y2024::day_03::solve_1(input.trim()).to_string(),
+        Some(|input| y2024::day_03::solve_2(input.trim()).to_string()),
+    ),
+    Day::new(
+        2024,
+        4,
+        |input| y2024::day_04::solve_1(&lines(input)).to_string(),
+        Some(|input| y2024::day_04::solve_2(&lines(input)).to_string()),
+    ),
+    Day::new(
+        2024,
+        5,
+        |input| y2024::day_05::solve_1(input.trim()).to_string(),
+        Some(|input| y2024::day_05::solve_2(input.trim()).to_string()),
+    ),
+    Day::new(
+        2024,
+        6,
+        |input| y2024::day_06::solve_1(&lines(input)).to_string(),
+        Some(|input| y2024::day_06::solve_2(&lines(input)).to_string()),
+    ),
+    Day::new(
+        2024,
+        7,
+        |input| y2024::day_07::solve_1(&lines(input)).to_string(),
+        Some(|input| y2024::day_07::solve_2(&lines(input)).to_string()),
+    ),
+    Day::new(
+        2024,
+        8,
+        |input| y2024::day_08::solve_1(&lines(input)).to_string(),
+        Some(|input| y2024::day_08::solve_2(&lines(input)).to_string()),
+    )
+    .with_parse(|input| {
+        black_box(y2024::day_08::Map::new(&lines(input)));
+    }),
+    Day::new(
+        2024,
+        9,
+        |input| y2024::day_09::solve_1(input.trim()).to_string(),
+        Some(|input| y2024::day_09::solve_2(input.trim()).to_string()),
+    )
+    .with_parse(|input| {
+        black_box(y2024::day_09::DiskMap::new(input.trim()));
+    }),
+    Day::new(
+        2024,
+        10,
+        |input| y2024::day_10::solve_1(&lines(input)).to_string(),
+        Some(|input| y2024::day_10::solve_2(&lines(input)).to_string()),
+    )
+    .with_parse(|input| {
+        black_box(y2024::day_10::Map::new(&lines(input)));
+    }),
+    Day::new(
+        2024,
+        11,
+        |input| y2024::day_11::solve_1(input.trim()).to_string(),
+        Some(|input| y2024::day_11::solve_2(input.trim()).to_string()),
+    )
+    .with_parse(|input| {
+        black_box(y2024::day_11::Engine::<u64>::new(
+            input.trim(),
+            y2024::day_11::Rules::default(),
+        ));
+    }),
+    Day::new(
+        2024,
+        12,
+        |input| y2024::day_12::solve_1(&lines(input)).to_string(),
+        Some(|input| y2024::day_12::solve_2(&lines(input)).to_string()),
+    ),
+    Day::new(
+        2024,
+        13,
+        |input| y2024::day_13::solve_1(input.trim()).to_string(),
+        Some(|input| y2024::day_13::solve_2(input.trim()).to_string()),
+    )
+    .with_parse(|input| {
+        input.trim().split("\n\n").for_each(|machine| {
+            black_box(y2024::day_13::Machine::new(machine, None));
+        })
+    }),
+    Day::new(
+        2024,
+        14,
+        |input| y2024::day_14::solve_1(&lines(input), &room()).to_string(),
+        Some(|input| y2024::day_14::solve_2(&lines(input), &room()).to_string()),
+    ),
+    Day::new(
+        2024,
+        15,
+        |input| y2024::day_15::solve_1(input.trim()).to_string(),
+        Some(|input| y2024::day_15::solve_2(input.trim()).to_string()),
+    ),
+    Day::new(
+        2024,
+        16,
+        |input| y2024::day_16::solve_1(&lines(input)).to_string(),
+        Some(|input| y2024::day_16::solve_2(&lines(input)).to_string()),
+    )
+    .with_parse(|input| {
+        black_box(y2024::day_16::Maze::new(
+            &lines(input),
+            &y2024::day_16::Rules::default(),
+        ));
+    }),
+    Day::new(
+        2024,
+        17,
+        |input| y2024::day_17::solve_1(&lines(input)).to_string(),
+        Some(|input| y2024::day_17::solve_2(&lines(input)).to_string()),
+    ),
+    Day::new(
+        2024,
+        18,
+        |input| y2024::day_18::solve_1(&lines(input), 1024, 70).to_string(),
+        Some(|input| y2024::day_18::solve_2(&lines(input), 70).to_string()),
+    ),
+    Day::new(
+        2024,
+        19,
+        |input| y2024::day_19::solve_1(&lines(input)).to_string(),
+        Some(|input| y2024::day_19::solve_2(&lines(input)).to_string()),
+    )
+    .with_parse(|input| {
+        black_box(y2024::day_19::Onsen::new(input.lines().next().unwrap()));
+    }),
+    Day::new(
+        2024,
+        20,
+        |input| y2024::day_20::solve_1(&lines(input), 100).to_string(),
+        Some(|input| y2024::day_20::solve_2(&lines(input), 100).to_string()),
+    )
+    .with_parse(|input| {
+        black_box(y2024::day_20::Track::new(&lines(input)));
+    }),
+    Day::new(
+        2024,
+        21,
+        |input| y2024::day_21::solve_1(&lines(input)).to_string(),
+        Some(|input| y2024::day_21::solve_2(&lines(input)).to_string()),
+    ),
+    Day::new(
+        2024,
+        22,
+        |input| y2024::day_22::solve_1(&lines(input)).to_string(),
+        Some(|input| y2024::day_22::solve_2(&lines(input)).to_string()),
+    )
+    .with_parse(|input| {
+        black_box(y2024::day_22::Market::new(
+            &lines(input),
+            y2024::day_22::Prng::default(),
+        ));
+    }),
+    Day::new(
+        2024,
+        23,
+        |input| y2024::day_23::solve_1(&lines(input)).to_string(),
+        Some(|input| y2024::day_23::solve_2(&lines(input)).to_string()),
+    )
+    .with_parse(|input| {
+        black_box(y2024::day_23::Network::new(&lines(input)));
+    }),
+    Day::new(
+        2024,
+        24,
+        |input| y2024::day_24::solve_1(input.trim()).to_string(),
+        Some(|input| y2024::day_24::solve_2(input.trim()).to_string()),
+    ),
+    Day::new(
+        2024,
+        25,
+        |input| y2024::day_25::solve_1(input.trim()).to_string(),
+        None,
+    )
+    .with_parse(|input| {
+        black_box(y2024::day_25::Schematics::from_str(input.trim()).unwrap());
+    }),
+    // scaffold: new days go above this line
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use itertools::Itertools;
+
+    #[test]
+    fn registry_days() {
+        assert!(days().iter().map(|day| (day.year, day.day)).all_unique());
+
+        let day = find(2024, 1).unwrap();
+        let sample = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+        assert_eq!("11", (day.part_1)(sample));
+        assert_eq!(
+            Some("31".to_string()),
+            day.solver(2).map(|solve| solve(sample))
+        );
+        assert!(find(2024, 25).unwrap().solver(2).is_none());
+        assert!(find(2023, 1).is_none());
+    }
+}
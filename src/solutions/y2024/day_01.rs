@@ -0,0 +1,278 @@
+use crate::util;
+use itertools::Itertools;
+use std::collections::BTreeMap;
+use std::io;
+use std::io::BufRead;
+
+pub fn solve_1(locations: &str) -> u32 {
+    Histogram::from_lines(locations.lines()).sorted_distance() as u32
+}
+
+pub fn solve_2(locations: &str) -> usize {
+    Histogram::from_lines(locations.lines()).similarity() as usize
+}
+
+/// Same as [`solve_1`], reading the locations line by line.
+pub fn solve_1_from(locations: impl BufRead) -> io::Result<u32> {
+    Ok(Histogram::from_reader(locations)?.sorted_distance() as u32)
+}
+
+/// Same as [`solve_2`], reading the locations line by line.
+pub fn solve_2_from(locations: impl BufRead) -> io::Result<usize> {
+    Ok(Histogram::from_reader(locations)?.similarity() as usize)
+}
+
+pub fn compare(left: &[i32], right: &[i32]) -> Metrics {
+    let histogram = Histogram::from_lists(left, right);
+
+    Metrics {
+        sorted_distance: (left.len() == right.len()).then(|| histogram.sorted_distance()),
+        similarity: histogram.similarity(),
+        assignment_cost: assignment_cost(left, right),
+        intersection: histogram.intersection(),
+        left_difference: histogram.left_difference(),
+        right_difference: histogram.right_difference(),
+        histogram,
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Metrics {
+    /// Only defined when both lists have the same length.
+    pub sorted_distance: Option<u64>,
+    /// Negative for lists sharing mostly negative IDs.
+    pub similarity: i64,
+    pub assignment_cost: u64,
+    pub intersection: BTreeMap<i32, u64>,
+    pub left_difference: BTreeMap<i32, u64>,
+    pub right_difference: BTreeMap<i32, u64>,
+    pub histogram: Histogram,
+}
+
+/// Occurrences of every location ID in the left and right list.
+///
+/// Only the distinct IDs are kept in memory, so it can be built line by line
+/// from inputs too large to be collected and sorted as a whole.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Histogram {
+    counts: BTreeMap<i32, (u64, u64)>,
+}
+
+impl Histogram {
+    pub fn from_lines<'a>(lines: impl IntoIterator<Item = &'a str>) -> Self {
+        let mut histogram = Self::default();
+
+        lines
+            .into_iter()
+            .filter(|line| !line.trim().is_empty())
+            .for_each(|line| histogram.add_line(line));
+
+        histogram
+    }
+
+    pub fn from_reader(lines: impl BufRead) -> io::Result<Self> {
+        let mut histogram = Self::default();
+        util::for_each_line(lines, |line| histogram.add_line(line))?;
+
+        Ok(histogram)
+    }
+
+    fn add_line(&mut self, line: &str) {
+        let (left, right) = line
+            .split_whitespace()
+            .map(|s| s.parse::<i32>().unwrap())
+            .collect_tuple()
+            .unwrap();
+        self.add(left, right);
+    }
+
+    pub fn from_lists(left: &[i32], right: &[i32]) -> Self {
+        let mut histogram = Self::default();
+
+        left.iter()
+            .for_each(|&id| histogram.counts.entry(id).or_default().0 += 1);
+        right
+            .iter()
+            .for_each(|&id| histogram.counts.entry(id).or_default().1 += 1);
+
+        histogram
+    }
+
+    pub fn add(&mut self, left: i32, right: i32) {
+        self.counts.entry(left).or_default().0 += 1;
+        self.counts.entry(right).or_default().1 += 1;
+    }
+
+    pub fn counts(&self) -> &BTreeMap<i32, (u64, u64)> {
+        &self.counts
+    }
+
+    /// Sum of distances between both lists once sorted, without sorting them.
+    ///
+    /// Walking the IDs in order, the number of pairs straddling the gap up to the next ID
+    /// is the difference between how many IDs either list has seen so far.
+    pub fn sorted_distance(&self) -> u64 {
+        let (nr_left, nr_right) = self
+            .counts
+            .values()
+            .fold((0, 0), |(l, r), (c_l, c_r)| (l + c_l, r + c_r));
+        assert_eq!(nr_left, nr_right, "both lists need to be equally long");
+
+        let mut seen = (0i64, 0i64);
+
+        self.counts
+            .iter()
+            .tuple_windows()
+            .map(|((&id, &(c_l, c_r)), (&next_id, _))| {
+                seen = (seen.0 + c_l as i64, seen.1 + c_r as i64);
+                (seen.0 - seen.1).unsigned_abs() * id.abs_diff(next_id) as u64
+            })
+            .sum()
+    }
+
+    pub fn similarity(&self) -> i64 {
+        self.counts
+            .iter()
+            .map(|(&id, &(c_l, c_r))| id as i64 * c_l as i64 * c_r as i64)
+            .sum()
+    }
+
+    pub fn intersection(&self) -> BTreeMap<i32, u64> {
+        self.filter_counts(|c_l, c_r| c_l.min(c_r))
+    }
+
+    /// IDs left over in the left list once the right one is taken away.
+    pub fn left_difference(&self) -> BTreeMap<i32, u64> {
+        self.filter_counts(|c_l, c_r| c_l.saturating_sub(c_r))
+    }
+
+    /// IDs left over in the right list once the left one is taken away.
+    pub fn right_difference(&self) -> BTreeMap<i32, u64> {
+        self.filter_counts(|c_l, c_r| c_r.saturating_sub(c_l))
+    }
+
+    fn filter_counts(&self, count: impl Fn(u64, u64) -> u64) -> BTreeMap<i32, u64> {
+        self.counts
+            .iter()
+            .map(|(&id, &(c_l, c_r))| (id, count(c_l, c_r)))
+            .filter(|(_, count)| *count > 0)
+            .collect()
+    }
+}
+
+/// Cheapest way to pair every ID of the shorter list with a distinct ID of the longer one.
+///
+/// On a line the optimal pairing never crosses, so once sorted it is a matter of choosing
+/// which IDs of the longer list to skip, at most "len difference" of them.
+pub fn assignment_cost(left: &[i32], right: &[i32]) -> u64 {
+    let (short, long) = if left.len() <= right.len() {
+        (left, right)
+    } else {
+        (right, left)
+    };
+    let short = short.iter().sorted().collect_vec();
+    let long = long.iter().sorted().collect_vec();
+    let slack = long.len() - short.len();
+
+    // costs[skipped] holds the cost of pairing the IDs seen so far having skipped that many
+    let mut costs = vec![0u64; slack + 1];
+
+    for (idx, id) in short.iter().enumerate() {
+        let mut best = u64::MAX;
+
+        for (skipped, cost) in costs.iter_mut().enumerate() {
+            best = best.min(*cost);
+            *cost = best + id.abs_diff(*long[idx + skipped]) as u64;
+        }
+    }
+
+    costs.into_iter().min().unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers, inputs};
+
+    #[test]
+    fn day_01_part_01_sample() {
+        let sample = "\
+                3   4
+                4   3
+                2   5
+                1   3
+                3   9
+                3   3\
+            ";
+
+        assert_eq!(11, solve_1(sample));
+    }
+
+    #[test]
+    fn day_01_part_01_solution() {
+        let Some(input) = inputs::load(2024, 1) else {
+            return;
+        };
+        let input = input.trim();
+
+        assert_eq!(answers::expected(2024, 1, 1), solve_1(input).to_string());
+    }
+
+    #[test]
+    fn day_01_part_02_sample() {
+        let sample = "\
+                3   4
+                4   3
+                2   5
+                1   3
+                3   9
+                3   3\
+            ";
+
+        assert_eq!(31, solve_2(sample));
+    }
+
+    #[test]
+    fn day_01_part_02_solution() {
+        let Some(input) = inputs::load(2024, 1) else {
+            return;
+        };
+        let input = input.trim();
+
+        assert_eq!(answers::expected(2024, 1, 2), solve_2(input).to_string());
+    }
+
+    #[test]
+    fn day_01_streamed() {
+        let sample = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+
+        assert_eq!(11, solve_1_from(sample.as_bytes()).unwrap());
+        assert_eq!(31, solve_2_from(sample.as_bytes()).unwrap());
+    }
+
+    #[test]
+    fn day_01_compare() {
+        let metrics = compare(&[3, 4, 2, 1, 3, 3], &[4, 3, 5, 3, 9, 3]);
+
+        assert_eq!(Some(11), metrics.sorted_distance);
+        assert_eq!(31, metrics.similarity);
+        assert_eq!(11, metrics.assignment_cost);
+        assert_eq!(BTreeMap::from([(3, 3), (4, 1)]), metrics.intersection);
+        assert_eq!(BTreeMap::from([(1, 1), (2, 1)]), metrics.left_difference);
+        assert_eq!(BTreeMap::from([(5, 1), (9, 1)]), metrics.right_difference);
+        assert_eq!(Some(&(3, 3)), metrics.histogram.counts().get(&3));
+
+        let negative = compare(&[-1, -1, 2], &[-1, -1, 5]);
+        assert_eq!(-4, negative.similarity);
+        assert_eq!(Some(3), negative.sorted_distance);
+    }
+
+    #[test]
+    fn day_01_assignment_cost() {
+        assert_eq!(2, assignment_cost(&[1, 10], &[0, 5, 11, 100]));
+        assert_eq!(0, assignment_cost(&[], &[1, 2]));
+
+        let metrics = compare(&[1, 10], &[0, 5, 11, 100]);
+        assert_eq!(None, metrics.sorted_distance);
+    }
+}
@@ -0,0 +1,345 @@
+use crate::{parallel, util};
+use itertools::Itertools;
+use std::io;
+use std::io::BufRead;
+use std::ops::Range;
+
+pub fn solve_1(reports: &[&str]) -> usize {
+    solve(reports, false)
+}
+
+pub fn solve_2(reports: &[&str]) -> usize {
+    solve(reports, true)
+}
+
+pub fn solve(reports: &[&str], problem_dampener: bool) -> usize {
+    parallel::fold(
+        reports,
+        || 0,
+        |safe, report| safe + Report::new(report).is_safe(problem_dampener) as usize,
+        |a, b| a + b,
+    )
+}
+
+/// Same as [`solve_1`], reading the reports line by line.
+pub fn solve_1_from(reports: impl BufRead) -> io::Result<usize> {
+    solve_from(reports, false)
+}
+
+/// Same as [`solve_2`], reading the reports line by line.
+pub fn solve_2_from(reports: impl BufRead) -> io::Result<usize> {
+    solve_from(reports, true)
+}
+
+/// Same as [`solve`], reading the reports line by line, keeping a single one in memory at a time.
+pub fn solve_from(reports: impl BufRead, problem_dampener: bool) -> io::Result<usize> {
+    let mut safe = 0;
+    util::for_each_line(reports, |report| {
+        if Report::new(report).is_safe(problem_dampener) {
+            safe += 1;
+        }
+    })?;
+
+    Ok(safe)
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct StepBounds {
+    pub min: u32,
+    pub max: u32,
+}
+
+impl Default for StepBounds {
+    fn default() -> Self {
+        Self { min: 1, max: 3 }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum Violation {
+    DirectionChange,
+    StepTooLarge,
+    StepTooSmall,
+    StepOfZero,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Diagnostic {
+    /// Index of the level that breaks the report, the step leading up to it is the faulty one.
+    pub index: usize,
+    pub violation: Violation,
+}
+
+#[derive(Debug)]
+pub struct Report {
+    levels: Vec<i32>,
+}
+
+impl Report {
+    pub fn new(report: &str) -> Self {
+        let levels = report
+            .split_whitespace()
+            .map(|level| level.parse().unwrap())
+            .collect();
+
+        Self { levels }
+    }
+
+    fn is_safe(&self, problem_dampener: bool) -> bool {
+        let safe = self.diagnose(&StepBounds::default()).is_none();
+        if !problem_dampener {
+            return safe;
+        }
+
+        safe || self.min_removals(&StepBounds::default()) <= 1
+    }
+
+    /// Finds the first step violating the bounds, the direction is set by the very first step.
+    pub fn diagnose(&self, bounds: &StepBounds) -> Option<Diagnostic> {
+        let sign = self
+            .levels
+            .get(1)
+            .map(|second| i32::signum(second - self.levels[0]))?;
+
+        (1..self.levels.len()).find_map(|index| {
+            let delta = self.levels[index] - self.levels[index - 1];
+
+            let violation = match delta.unsigned_abs() {
+                0 => Some(Violation::StepOfZero),
+                _ if delta.signum() != sign => Some(Violation::DirectionChange),
+                step if step > bounds.max => Some(Violation::StepTooLarge),
+                step if step < bounds.min => Some(Violation::StepTooSmall),
+                _ => None,
+            };
+
+            violation.map(|violation| Diagnostic { index, violation })
+        })
+    }
+
+    pub fn min_removals(&self, bounds: &StepBounds) -> usize {
+        self.levels_to_remove(bounds).len()
+    }
+
+    /// Lists the fewest levels to remove for the report to become safe.
+    pub fn levels_to_remove(&self, bounds: &StepBounds) -> Vec<usize> {
+        let increasing = self.longest_safe_chain(bounds, 1);
+        let decreasing = self.longest_safe_chain(bounds, -1);
+        let kept = if increasing.len() >= decreasing.len() {
+            increasing
+        } else {
+            decreasing
+        };
+
+        let mut kept = kept.into_iter().rev().peekable();
+        (0..self.levels.len())
+            .filter(|&idx| kept.next_if_eq(&idx).is_none())
+            .collect()
+    }
+
+    /// Longest subsequence of levels whose consecutive steps are all within bounds
+    /// in the given direction, returned as indices from last to first.
+    ///
+    /// Keeping track of the best chain ending in each value, every level looks up the best
+    /// among the values it could be stepping from, which form a range of the sorted values.
+    /// With a max tree over those, this takes O(n log n) whatever the bounds.
+    fn longest_safe_chain(&self, bounds: &StepBounds, sign: i64) -> Vec<usize> {
+        let values = self
+            .levels
+            .iter()
+            .map(|&level| sign * level as i64)
+            .collect::<Vec<_>>();
+        let sorted = values.iter().copied().sorted().dedup().collect::<Vec<_>>();
+        let (min_step, max_step) = (bounds.min.max(1) as i64, bounds.max as i64);
+
+        let mut best_per_value = MaxTree::new(sorted.len());
+        let mut chains: Vec<(usize, Option<usize>)> = Vec::with_capacity(values.len());
+
+        for (idx, &value) in values.iter().enumerate() {
+            let from = sorted.partition_point(|&previous| previous < value - max_step);
+            let to = sorted.partition_point(|&previous| previous <= value - min_step);
+
+            let chain = match best_per_value.max(from..to) {
+                Some((len, previous_idx)) => (len + 1, Some(previous_idx)),
+                None => (1, None),
+            };
+            chains.push(chain);
+
+            best_per_value.raise(sorted.binary_search(&value).unwrap(), (chain.0, idx));
+        }
+
+        let mut idx = (0..chains.len()).max_by_key(|&idx| chains[idx].0);
+        let mut chain = vec![];
+        while let Some(current) = idx {
+            chain.push(current);
+            idx = chains[current].1;
+        }
+
+        chain
+    }
+}
+
+/// Segment tree of the best `(length, index)` chain ending in each of the sorted values.
+struct MaxTree {
+    nodes: Vec<Option<(usize, usize)>>,
+}
+
+impl MaxTree {
+    fn new(len: usize) -> Self {
+        Self {
+            nodes: vec![None; 2 * len],
+        }
+    }
+
+    /// Replaces the chain at the position, unless it already holds a longer or equal one.
+    fn raise(&mut self, position: usize, chain: (usize, usize)) {
+        let mut node = position + self.nodes.len() / 2;
+        if self.nodes[node].is_some_and(|best| best.0 >= chain.0) {
+            return;
+        }
+
+        self.nodes[node] = Some(chain);
+        while node > 1 {
+            node /= 2;
+            self.nodes[node] = self.nodes[2 * node].max(self.nodes[2 * node + 1]);
+        }
+    }
+
+    fn max(&self, positions: Range<usize>) -> Option<(usize, usize)> {
+        let offset = self.nodes.len() / 2;
+        let (mut from, mut to) = (positions.start + offset, positions.end + offset);
+
+        let mut max = None;
+        while from < to {
+            if from % 2 == 1 {
+                max = max.max(self.nodes[from]);
+                from += 1;
+            }
+            if to % 2 == 1 {
+                to -= 1;
+                max = max.max(self.nodes[to]);
+            }
+            from /= 2;
+            to /= 2;
+        }
+
+        max
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::*;
+    use crate::{answers, inputs};
+
+    #[test]
+    fn day_02_part_01_sample() {
+        let sample = vec![
+            "7 6 4 2 1",
+            "1 2 7 8 9",
+            "9 7 6 2 1",
+            "1 3 2 4 5",
+            "8 6 4 4 1",
+            "1 3 6 7 9",
+        ];
+
+        assert_eq!(2, solve_1(&sample));
+    }
+
+    #[test]
+    fn day_02_part_01_solution() {
+        let Some(input) = inputs::load(2024, 2) else {
+            return;
+        };
+        let input = input.lines().collect_vec();
+
+        assert_eq!(answers::expected(2024, 2, 1), solve_1(&input).to_string());
+    }
+
+    #[test]
+    fn day_02_part_02_sample() {
+        let sample = vec![
+            "7 6 4 2 1",
+            "1 2 7 8 9",
+            "9 7 6 2 1",
+            "1 3 2 4 5",
+            "8 6 4 4 1",
+            "1 3 6 7 9",
+        ];
+
+        assert_eq!(4, solve_2(&sample));
+    }
+
+    #[test]
+    fn day_02_part_02_solution() {
+        let Some(input) = inputs::load(2024, 2) else {
+            return;
+        };
+        let input = input.lines().collect_vec();
+
+        assert_eq!(answers::expected(2024, 2, 2), solve_2(&input).to_string());
+    }
+
+    #[test]
+    fn day_02_streamed() {
+        let sample = "7 6 4 2 1\n1 2 7 8 9\n9 7 6 2 1\n1 3 2 4 5\n8 6 4 4 1\n1 3 6 7 9\n";
+
+        assert_eq!(2, solve_1_from(sample.as_bytes()).unwrap());
+        assert_eq!(4, solve_2_from(sample.as_bytes()).unwrap());
+    }
+
+    #[test]
+    fn day_02_diagnostics() {
+        let bounds = StepBounds::default();
+        let diagnose = |report| Report::new(report).diagnose(&bounds);
+
+        assert_eq!(None, diagnose("7 6 4 2 1"));
+        assert_eq!(
+            Some(Diagnostic {
+                index: 2,
+                violation: Violation::StepTooLarge
+            }),
+            diagnose("1 2 7 8 9")
+        );
+        assert_eq!(
+            Some(Diagnostic {
+                index: 2,
+                violation: Violation::DirectionChange
+            }),
+            diagnose("1 3 2 4 5")
+        );
+        assert_eq!(
+            Some(Diagnostic {
+                index: 3,
+                violation: Violation::StepOfZero
+            }),
+            diagnose("8 6 4 4 1")
+        );
+    }
+
+    #[test]
+    fn day_02_levels_to_remove() {
+        let bounds = StepBounds::default();
+
+        assert_eq!(
+            vec![2, 3],
+            Report::new("1 2 7 8 3 4").levels_to_remove(&bounds)
+        );
+        assert_eq!(2, Report::new("9 7 6 2 1").min_removals(&bounds));
+        assert_eq!(
+            vec![1],
+            Report::new("1 2 5 9").levels_to_remove(&StepBounds { min: 4, max: 4 })
+        );
+
+        let unbounded = StepBounds {
+            min: 1,
+            max: u32::MAX,
+        };
+        assert_eq!(0, Report::new("1 2 5 9").min_removals(&unbounded));
+        assert_eq!(
+            vec![0, 2],
+            Report::new("5 1 100 2 3").levels_to_remove(&unbounded)
+        );
+    }
+}
@@ -28,6 +28,7 @@ fn solve(memory: &str) -> u32 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{answers, inputs};
 
     #[test]
     fn day_03_part_01_sample() {
@@ -38,9 +39,12 @@ mod tests {
 
     #[test]
     fn day_03_part_01_solution() {
-        let input = include_str!("../../inputs/day_03.txt").trim();
+        let Some(input) = inputs::load(2024, 3) else {
+            return;
+        };
+        let input = input.trim();
 
-        assert_eq!(183_380_722, solve_1(input));
+        assert_eq!(answers::expected(2024, 3, 1), solve_1(input).to_string());
     }
 
     #[test]
@@ -52,8 +56,11 @@ mod tests {
 
     #[test]
     fn day_03_part_02_solution() {
-        let input = include_str!("../../inputs/day_03.txt").trim();
+        let Some(input) = inputs::load(2024, 3) else {
+            return;
+        };
+        let input = input.trim();
 
-        assert_eq!(82_733_683, solve_2(input));
+        assert_eq!(answers::expected(2024, 3, 2), solve_2(input).to_string());
     }
 }
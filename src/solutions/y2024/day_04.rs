@@ -93,6 +93,7 @@ mod tests {
     use itertools::Itertools;
 
     use super::*;
+    use crate::{answers, inputs};
 
     #[test]
     fn day_04_part_01_sample() {
@@ -114,11 +115,12 @@ mod tests {
 
     #[test]
     fn day_04_part_01_solution() {
-        let input = include_str!("../../inputs/day_04.txt")
-            .lines()
-            .collect_vec();
+        let Some(input) = inputs::load(2024, 4) else {
+            return;
+        };
+        let input = input.lines().collect_vec();
 
-        assert_eq!(2_458, solve_1(&input));
+        assert_eq!(answers::expected(2024, 4, 1), solve_1(&input).to_string());
     }
 
     #[test]
@@ -141,10 +143,11 @@ mod tests {
 
     #[test]
     fn day_04_part_02_solution() {
-        let input = include_str!("../../inputs/day_04.txt")
-            .lines()
-            .collect_vec();
+        let Some(input) = inputs::load(2024, 4) else {
+            return;
+        };
+        let input = input.lines().collect_vec();
 
-        assert_eq!(1_945, solve_2(&input));
+        assert_eq!(answers::expected(2024, 4, 2), solve_2(&input).to_string());
     }
 }
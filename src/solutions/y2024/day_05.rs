@@ -96,6 +96,7 @@ impl Update {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{answers, inputs};
 
     #[test]
     fn day_05_part_01_sample() {
@@ -135,9 +136,12 @@ mod tests {
 
     #[test]
     fn day_05_part_01_solution() {
-        let input = include_str!("../../inputs/day_05.txt").trim();
+        let Some(input) = inputs::load(2024, 5) else {
+            return;
+        };
+        let input = input.trim();
 
-        assert_eq!(6_034, solve_1(input));
+        assert_eq!(answers::expected(2024, 5, 1), solve_1(input).to_string());
     }
 
     #[test]
@@ -178,8 +182,11 @@ mod tests {
 
     #[test]
     fn day_05_part_02_solution() {
-        let input = include_str!("../../inputs/day_05.txt").trim();
+        let Some(input) = inputs::load(2024, 5) else {
+            return;
+        };
+        let input = input.trim();
 
-        assert_eq!(6_305, solve_2(input));
+        assert_eq!(answers::expected(2024, 5, 2), solve_2(input).to_string());
     }
 }
@@ -1,6 +1,5 @@
+use crate::parallel;
 use itertools::Itertools;
-use rayon::iter::IntoParallelRefIterator;
-use rayon::iter::ParallelIterator;
 use rustc_hash::FxHashSet;
 use std::ops::Not;
 
@@ -12,23 +11,23 @@ pub fn solve_1(map: &[&str]) -> usize {
 pub fn solve_2(map: &[&str]) -> usize {
     let map = Map::new(map);
     let (guards, _) = map.walk();
-    let original_path: FxHashSet<_> = guards
+    let original_path = guards
         .iter()
         .map(|guard| guard.coordinate)
         .unique()
         .filter(|c| &map.guard.coordinate != c)
-        .collect();
+        .collect_vec();
 
-    original_path
-        .par_iter()
-        .map(|c| {
+    parallel::fold(
+        &original_path,
+        || 0,
+        |loops, c| {
             let mut map = map.clone();
             map.obstructions.insert(*c);
-            map
-        })
-        .map(|map| map.walk().1)
-        .filter(|&s| s == Status::Looping)
-        .count()
+            loops + (map.walk().1 == Status::Looping) as usize
+        },
+        |a, b| a + b,
+    )
 }
 
 #[derive(Debug, Clone)]
@@ -165,6 +164,7 @@ mod tests {
     use itertools::Itertools;
 
     use super::*;
+    use crate::{answers, inputs};
 
     #[test]
     fn day_06_part_01_sample() {
@@ -186,11 +186,12 @@ mod tests {
 
     #[test]
     fn day_06_part_01_solution() {
-        let input = include_str!("../../inputs/day_06.txt")
-            .lines()
-            .collect_vec();
+        let Some(input) = inputs::load(2024, 6) else {
+            return;
+        };
+        let input = input.lines().collect_vec();
 
-        assert_eq!(4_515, solve_1(&input));
+        assert_eq!(answers::expected(2024, 6, 1), solve_1(&input).to_string());
     }
 
     #[test]
@@ -213,10 +214,11 @@ mod tests {
 
     #[test]
     fn day_06_part_02_solution() {
-        let input = include_str!("../../inputs/day_06.txt")
-            .lines()
-            .collect_vec();
+        let Some(input) = inputs::load(2024, 6) else {
+            return;
+        };
+        let input = input.lines().collect_vec();
 
-        assert_eq!(1_309, solve_2(&input));
+        assert_eq!(answers::expected(2024, 6, 2), solve_2(&input).to_string());
     }
 }
@@ -1,4 +1,7 @@
+use crate::{parallel, util};
 use itertools::Itertools;
+use std::io;
+use std::io::BufRead;
 
 pub fn solve_1(calibrations: &[&str]) -> u64 {
     solve(calibrations, false)
@@ -9,12 +12,38 @@ pub fn solve_2(calibrations: &[&str]) -> u64 {
 }
 
 fn solve(calibrations: &[&str], concat: bool) -> u64 {
-    calibrations
-        .iter()
-        .map(|c| Calibration::new(c, concat))
-        .filter(|c| c.can_solve())
-        .map(|c| c.test_value)
-        .sum()
+    parallel::fold(
+        calibrations,
+        || 0,
+        |total, c| {
+            let c = Calibration::new(c, concat);
+            total + if c.can_solve() { c.test_value } else { 0 }
+        },
+        |a, b| a + b,
+    )
+}
+
+/// Same as [`solve_1`], reading the calibrations line by line.
+pub fn solve_1_from(calibrations: impl BufRead) -> io::Result<u64> {
+    solve_from(calibrations, false)
+}
+
+/// Same as [`solve_2`], reading the calibrations line by line.
+pub fn solve_2_from(calibrations: impl BufRead) -> io::Result<u64> {
+    solve_from(calibrations, true)
+}
+
+/// Keeps a single calibration in memory at a time.
+fn solve_from(calibrations: impl BufRead, concat: bool) -> io::Result<u64> {
+    let mut total = 0;
+    util::for_each_line(calibrations, |calibration| {
+        let calibration = Calibration::new(calibration, concat);
+        if calibration.can_solve() {
+            total += calibration.test_value;
+        }
+    })?;
+
+    Ok(total)
 }
 
 struct Calibration {
@@ -75,6 +104,7 @@ mod tests {
     use itertools::Itertools;
 
     use super::*;
+    use crate::{answers, inputs};
 
     #[test]
     fn day_07_part_01_sample() {
@@ -95,11 +125,12 @@ mod tests {
 
     #[test]
     fn day_07_part_01_solution() {
-        let input = include_str!("../../inputs/day_07.txt")
-            .lines()
-            .collect_vec();
+        let Some(input) = inputs::load(2024, 7) else {
+            return;
+        };
+        let input = input.lines().collect_vec();
 
-        assert_eq!(975_671_981_569, solve_1(&input));
+        assert_eq!(answers::expected(2024, 7, 1), solve_1(&input).to_string());
     }
 
     #[test]
@@ -121,10 +152,21 @@ mod tests {
 
     #[test]
     fn day_07_part_02_solution() {
-        let input = include_str!("../../inputs/day_07.txt")
-            .lines()
-            .collect_vec();
+        let Some(input) = inputs::load(2024, 7) else {
+            return;
+        };
+        let input = input.lines().collect_vec();
+
+        assert_eq!(answers::expected(2024, 7, 2), solve_2(&input).to_string());
+    }
+
+    #[test]
+    fn day_07_streamed() {
+        let sample = "\
+            190: 10 19\n3267: 81 40 27\n83: 17 5\n156: 15 6\n7290: 6 8 6 15\n\
+            161011: 16 10 13\n192: 17 8 14\n21037: 9 7 18 13\n292: 11 6 16 20\n";
 
-        assert_eq!(223_472_064_194_845, solve_2(&input));
+        assert_eq!(3_749, solve_1_from(sample.as_bytes()).unwrap());
+        assert_eq!(11_387, solve_2_from(sample.as_bytes()).unwrap());
     }
 }
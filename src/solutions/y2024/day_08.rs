@@ -0,0 +1,326 @@
+use crate::util;
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+
+pub fn solve_1(map: &[&str]) -> usize {
+    Map::new(map)
+        .antinodes(&Resonance::Ratios {
+            ratios: vec![(2, 1)],
+            between: false,
+        })
+        .len()
+}
+
+pub fn solve_2(map: &[&str]) -> usize {
+    Map::new(map)
+        .antinodes(&Resonance::Harmonics {
+            reduced_step: false,
+        })
+        .len()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Resonance {
+    /// An antinode sits wherever one antenna is `far / near` times as far away as the other.
+    /// Points in between both antennas are only included when `between` is set.
+    /// Ratios with a zero term are ignored.
+    Ratios {
+        ratios: Vec<(u32, u32)>,
+        between: bool,
+    },
+    /// An antinode sits on every grid point in line with both antennas.
+    /// Without `reduced_step` the line is walked outwards by the raw delta between the antennas,
+    /// with it the delta is first divided by its gcd so no collinear grid point is missed.
+    Harmonics { reduced_step: bool },
+}
+
+pub type AntennaPair = (Location, Location);
+
+#[derive(Debug)]
+pub struct Map {
+    antennas: HashMap<char, Vec<Location>>,
+    width: i32,
+    height: i32,
+}
+
+impl Map {
+    pub fn new(map: &[&str]) -> Self {
+        let antennas = map
+            .iter()
+            .enumerate()
+            .flat_map(|(y, line)| {
+                line.chars().enumerate().map(move |(x, c)| {
+                    let x = x as i32;
+                    let y = y as i32;
+                    (c, Location { x, y })
+                })
+            })
+            .filter(|(c, _)| *c != '.')
+            .sorted_by_key(|(c, _)| *c)
+            .chunk_by(|(c, _)| *c)
+            .into_iter()
+            .map(|(c, locations)| (c, locations.into_iter().map(|(_, l)| l).collect()))
+            .collect();
+        let width = map[0].len() as i32;
+        let height = map.len() as i32;
+
+        Self {
+            antennas,
+            width,
+            height,
+        }
+    }
+
+    pub fn antinodes(&self, resonance: &Resonance) -> HashSet<Location> {
+        self.antennas
+            .values()
+            .flat_map(|antenna| {
+                Self::pairs(antenna).flat_map(|(l1, l2)| self.antinodes_per_pair(l1, l2, resonance))
+            })
+            .collect()
+    }
+
+    /// Groups the antinodes by frequency, listing for every antinode the antenna pairs producing it.
+    pub fn antinodes_per_frequency(
+        &self,
+        resonance: &Resonance,
+    ) -> HashMap<char, HashMap<Location, Vec<AntennaPair>>> {
+        self.antennas
+            .iter()
+            .map(|(&frequency, antenna)| {
+                let mut antinodes: HashMap<Location, Vec<AntennaPair>> = HashMap::new();
+
+                Self::pairs(antenna).for_each(|(l1, l2)| {
+                    self.antinodes_per_pair(l1, l2, resonance)
+                        .into_iter()
+                        .for_each(|antinode| antinodes.entry(antinode).or_default().push((l1, l2)))
+                });
+
+                (frequency, antinodes)
+            })
+            .collect()
+    }
+
+    fn pairs(antenna: &[Location]) -> impl Iterator<Item = AntennaPair> + '_ {
+        (0..antenna.len())
+            .flat_map(move |i| ((i + 1)..antenna.len()).map(move |j| (antenna[i], antenna[j])))
+    }
+
+    fn antinodes_per_pair(
+        &self,
+        l1: Location,
+        l2: Location,
+        resonance: &Resonance,
+    ) -> Vec<Location> {
+        match resonance {
+            Resonance::Ratios { ratios, between } => ratios
+                .iter()
+                .filter(|(far, near)| *far > 0 && *near > 0)
+                .map(|&(far, near)| {
+                    // Ratios like 2/1, 1/2 and 4/2 all describe the same antinodes
+                    let gcd = util::gcd(far as u64, near as u64) as i64;
+                    (far.max(near) as i64 / gcd, far.min(near) as i64 / gcd)
+                })
+                .unique()
+                .flat_map(|(far, near)| {
+                    // Walking from an antenna away from (or towards) its partner by a fraction
+                    // "t" of the delta between both, the distance ratio is |1 + t| / |t|,
+                    // solving for "far / near" yields these fractions
+                    let mut fractions = vec![];
+                    if far != near {
+                        fractions.push((near, far - near));
+                    }
+                    if *between {
+                        fractions.push((-near, far + near));
+                    }
+
+                    fractions.into_iter().flat_map(move |(num, den)| {
+                        [l1.offset_by(l2, num, den), l2.offset_by(l1, num, den)]
+                            .into_iter()
+                            .flatten()
+                    })
+                })
+                .filter(|l| l.is_in_bounds(self))
+                // Both antennas are equally far from the point halfway between them
+                .unique()
+                .collect(),
+            Resonance::Harmonics { reduced_step } => {
+                let (mut dx, mut dy) = (l1.x - l2.x, l1.y - l2.y);
+
+                if *reduced_step {
+                    let gcd = util::gcd(dx.unsigned_abs() as u64, dy.unsigned_abs() as u64) as i32;
+                    dx /= gcd;
+                    dy /= gcd;
+
+                    // Walking the full line from one antenna covers the other one as well
+                    [(l1, dx, dy), (l1, -dx, -dy)]
+                        .into_iter()
+                        .flat_map(|(antinode, dx, dy)| self.walk(antinode, dx, dy))
+                        .unique()
+                        .collect()
+                } else {
+                    [(l1, dx, dy), (l2, -dx, -dy)]
+                        .into_iter()
+                        .flat_map(|(antinode, dx, dy)| self.walk(antinode, dx, dy))
+                        .collect()
+                }
+            }
+        }
+    }
+
+    fn walk(&self, from: Location, dx: i32, dy: i32) -> Vec<Location> {
+        (0..i32::MAX)
+            .map(|i| Location {
+                x: from.x + i * dx,
+                y: from.y + i * dy,
+            })
+            .take_while(|l| l.is_in_bounds(self))
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash)]
+pub struct Location {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Location {
+    fn is_in_bounds(&self, map: &Map) -> bool {
+        self.x >= 0 && self.x < map.width && self.y >= 0 && self.y < map.height
+    }
+
+    /// Moves away from `other` by `num / den` times the delta between both locations,
+    /// if that lands exactly on a grid point.
+    fn offset_by(&self, other: Location, num: i64, den: i64) -> Option<Location> {
+        let dx = (self.x - other.x) as i64 * num;
+        let dy = (self.y - other.y) as i64 * num;
+
+        (dx % den == 0 && dy % den == 0).then(|| Location {
+            x: self.x + (dx / den) as i32,
+            y: self.y + (dy / den) as i32,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::*;
+    use crate::{answers, inputs};
+
+    #[test]
+    fn day_08_part_01_sample() {
+        let sample = vec![
+            "............",
+            "........0...",
+            ".....0......",
+            ".......0....",
+            "....0.......",
+            "......A.....",
+            "............",
+            "............",
+            "........A...",
+            ".........A..",
+            "............",
+            "............",
+        ];
+
+        assert_eq!(14, solve_1(&sample));
+    }
+
+    #[test]
+    fn day_08_part_01_solution() {
+        let Some(input) = inputs::load(2024, 8) else {
+            return;
+        };
+        let input = input.lines().collect_vec();
+
+        assert_eq!(answers::expected(2024, 8, 1), solve_1(&input).to_string());
+    }
+
+    #[test]
+    fn day_08_part_02_sample() {
+        let sample = vec![
+            "............",
+            "........0...",
+            ".....0......",
+            ".......0....",
+            "....0.......",
+            "......A.....",
+            "............",
+            "............",
+            "........A...",
+            ".........A..",
+            "............",
+            "............",
+        ];
+
+        assert_eq!(34, solve_2(&sample));
+    }
+
+    #[test]
+    fn day_08_part_02_solution() {
+        let Some(input) = inputs::load(2024, 8) else {
+            return;
+        };
+        let input = input.lines().collect_vec();
+
+        assert_eq!(answers::expected(2024, 8, 2), solve_2(&input).to_string());
+    }
+
+    #[test]
+    fn day_08_reduced_step() {
+        #[rustfmt::skip]
+        let sample = vec![
+            "a....",
+            ".....",
+            "..a..",
+            ".....",
+            ".....",
+        ];
+        let map = Map::new(&sample);
+
+        let raw = map.antinodes(&Resonance::Harmonics {
+            reduced_step: false,
+        });
+        let reduced = map.antinodes(&Resonance::Harmonics { reduced_step: true });
+
+        assert_eq!(3, raw.len());
+        assert_eq!(5, reduced.len());
+        assert!(reduced.contains(&Location { x: 1, y: 1 }));
+    }
+
+    #[test]
+    fn day_08_antinodes_per_frequency() {
+        let sample = vec!["...A..A...B"];
+        let antinodes = Map::new(&sample).antinodes_per_frequency(&Resonance::Ratios {
+            ratios: vec![(2, 1)],
+            between: true,
+        });
+
+        let a = &antinodes[&'A'];
+        let pair = (Location { x: 3, y: 0 }, Location { x: 6, y: 0 });
+        assert_eq!(
+            vec![0, 4, 5, 9],
+            a.keys().map(|l| l.x).sorted().collect_vec()
+        );
+        assert!(a.values().all(|pairs| pairs == &vec![pair]));
+        assert!(antinodes[&'B'].is_empty());
+    }
+
+    #[test]
+    fn day_08_duplicate_ratios() {
+        let sample = vec!["A.....A"];
+        let antinodes = Map::new(&sample).antinodes_per_frequency(&Resonance::Ratios {
+            ratios: vec![(2, 1), (1, 2), (4, 2), (1, 1)],
+            between: true,
+        });
+
+        let a = &antinodes[&'A'];
+        let pair = (Location { x: 0, y: 0 }, Location { x: 6, y: 0 });
+        assert_eq!(vec![2, 3, 4], a.keys().map(|l| l.x).sorted().collect_vec());
+        assert!(a.values().all(|pairs| pairs == &vec![pair]));
+    }
+}
@@ -0,0 +1,462 @@
+use itertools::Itertools;
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+
+pub fn solve_1(disk: &str) -> u64 {
+    let mut disk: Vec<_> = disk
+        .chars()
+        .map(|c| c.to_digit(10).unwrap())
+        .enumerate()
+        .map(|(idx, d)| (idx as u64, d))
+        .flat_map(|(idx, d)| (0..d).map(move |_| if idx % 2 == 0 { idx / 2 } else { u64::MAX }))
+        .collect();
+    let mut space_idx = 0;
+    let mut file_idx = disk.len() - 1;
+
+    loop {
+        while space_idx < disk.len() && disk[space_idx] != u64::MAX {
+            space_idx += 1;
+        }
+        while disk[file_idx] == u64::MAX {
+            file_idx -= 1;
+        }
+
+        if space_idx > file_idx {
+            return disk
+                .iter()
+                .take_while(|&&f| f != u64::MAX)
+                .enumerate()
+                .map(|(idx, &f)| f * idx as u64)
+                .sum();
+        }
+
+        disk[space_idx] = disk[file_idx];
+        disk[file_idx] = u64::MAX;
+    }
+}
+
+pub fn solve_2(disk: &str) -> u64 {
+    let mut files: Vec<Block> = Vec::new();
+    let mut file = true;
+    let mut idx = 0;
+    let mut spaces = (0..10).map(|_| BinaryHeap::<Block>::new()).collect_vec();
+
+    for (id, d) in disk
+        .chars()
+        .enumerate()
+        .map(|(id, c)| (id as u64 / 2, c.to_digit(10).unwrap() as usize))
+    {
+        if file {
+            let file = Block { idx, len: d, id };
+            files.push(file);
+        } else {
+            let space = Block { idx, len: d, id: 0 };
+            spaces[space.len].push(space);
+        }
+
+        idx += d;
+        file = !file;
+    }
+
+    for file in files.iter_mut().rev() {
+        let first_space = (file.len..10)
+            .flat_map(|idx| spaces[idx].peek().map(|x| (idx, *x)))
+            .sorted_by_key(|&(_, b)| b)
+            .next_back()
+            .filter(|(_, space)| space.idx < file.idx);
+
+        if let Some((idx, _)) = first_space {
+            let mut space = spaces[idx].pop().unwrap();
+
+            file.idx = space.idx;
+            space.idx += file.len;
+            space.len -= file.len;
+
+            spaces[space.len].push(space);
+        }
+    }
+
+    files
+        .iter()
+        .flat_map(|f| (f.idx..f.idx + f.len).map(|idx| f.id * idx as u64))
+        .sum()
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+struct Block {
+    idx: usize,
+    len: usize,
+    id: u64,
+}
+
+impl Ord for Block {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.idx.cmp(&other.idx).reverse()
+    }
+}
+
+impl PartialOrd for Block {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum Strategy {
+    /// Moves single blocks from the end of the disk to the leftmost free block.
+    BlockWise,
+    /// Moves whole files, highest ID first, to the leftmost gap they fit in.
+    FirstFit,
+    /// Moves whole files, highest ID first, to the smallest gap they fit in.
+    BestFit,
+    /// Rewrites every file contiguously, one after the other, leaving no gaps.
+    Defragment,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Compaction {
+    pub disk: DiskMap,
+    pub checksum: u64,
+    pub moves: usize,
+}
+
+/// Block-level view on the disk, free blocks hold `None`.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct DiskMap {
+    blocks: Vec<Option<u64>>,
+    /// Files without any blocks, as `(id, idx)` of the block they precede.
+    empty_files: Vec<(u64, usize)>,
+}
+
+impl DiskMap {
+    pub fn new(disk: &str) -> Self {
+        let mut blocks = vec![];
+        let mut empty_files = vec![];
+
+        for (idx, d) in disk
+            .trim()
+            .chars()
+            .map(|c| c.to_digit(10).unwrap() as usize)
+            .enumerate()
+        {
+            let block = (idx % 2 == 0).then_some(idx as u64 / 2);
+            match block {
+                Some(id) if d == 0 => empty_files.push((id, blocks.len())),
+                _ => blocks.extend((0..d).map(|_| block)),
+            }
+        }
+
+        Self {
+            blocks,
+            empty_files,
+        }
+    }
+
+    pub fn blocks(&self) -> &[Option<u64>] {
+        &self.blocks
+    }
+
+    /// Writes the disk back in its dense format, if it can still be expressed in it:
+    /// files need to appear in order of ID, each in one piece, and no run can exceed 9 blocks.
+    ///
+    /// Empty files are written where they were parsed, or right before the next ID
+    /// once compacting left them nowhere in particular.
+    pub fn to_dense(&self) -> Option<String> {
+        let mut dense = String::new();
+        let mut next_id = 0;
+        let mut empty_files = self.empty_files.iter().peekable();
+        let mut segments = vec![];
+
+        for (block, idx, len) in self.runs() {
+            let mut start = idx;
+            while let Some(&(id, pos)) = empty_files.next_if(|(_, pos)| *pos < idx + len) {
+                if pos > start {
+                    segments.push((block, pos - start));
+                    start = pos;
+                }
+                segments.push((Some(id), 0));
+            }
+            segments.push((block, idx + len - start));
+        }
+        segments.extend(empty_files.map(|&(id, _)| (Some(id), 0)));
+
+        for (block, len) in segments {
+            match block {
+                Some(id) if id >= next_id => {
+                    // Skipped IDs are files without blocks
+                    for _ in next_id..id {
+                        if dense.len() % 2 == 1 {
+                            dense.push('0');
+                        }
+                        dense.push('0');
+                    }
+                    if dense.len() % 2 == 1 {
+                        dense.push('0');
+                    }
+                    next_id = id + 1;
+                }
+                None if dense.len() % 2 == 1 => {}
+                _ => return None,
+            }
+
+            dense.push(char::from_digit(len as u32, 10)?);
+        }
+
+        Some(dense)
+    }
+
+    /// Draws one character per block, with '.' for free blocks.
+    /// File IDs are written as base 36 digits, wrapping around past "z".
+    pub fn render(&self) -> String {
+        self.blocks
+            .iter()
+            .map(|block| match block {
+                Some(id) => char::from_digit((id % 36) as u32, 36).unwrap(),
+                None => '.',
+            })
+            .collect()
+    }
+
+    pub fn checksum(&self) -> u64 {
+        self.blocks
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, block)| block.map(|id| id * idx as u64))
+            .sum()
+    }
+
+    pub fn compact(&self, strategy: Strategy) -> Compaction {
+        let mut disk = self.clone();
+        disk.empty_files.clear();
+
+        let moves = match strategy {
+            Strategy::BlockWise => disk.compact_blocks(),
+            Strategy::FirstFit | Strategy::BestFit => disk.compact_files(strategy),
+            Strategy::Defragment => disk.defragment(),
+        };
+        let checksum = disk.checksum();
+
+        Compaction {
+            disk,
+            checksum,
+            moves,
+        }
+    }
+
+    fn compact_blocks(&mut self) -> usize {
+        let mut moves = 0;
+        let mut space_idx = 0;
+        let mut file_idx = self.blocks.len();
+
+        loop {
+            while space_idx < self.blocks.len() && self.blocks[space_idx].is_some() {
+                space_idx += 1;
+            }
+            while file_idx > 0 && self.blocks[file_idx - 1].is_none() {
+                file_idx -= 1;
+            }
+
+            if file_idx == 0 || space_idx >= file_idx - 1 {
+                return moves;
+            }
+
+            self.blocks.swap(space_idx, file_idx - 1);
+            moves += 1;
+        }
+    }
+
+    fn compact_files(&mut self, strategy: Strategy) -> usize {
+        let mut moves = 0;
+        let mut gaps = self
+            .runs()
+            .into_iter()
+            .filter(|(block, _, _)| block.is_none())
+            .map(|(_, idx, len)| (idx, len))
+            .collect_vec();
+        let files = self
+            .runs()
+            .into_iter()
+            .filter_map(|(block, idx, len)| block.map(|id| (id, idx, len)))
+            .sorted_by_key(|&(id, idx, _)| (Reverse(id), Reverse(idx)))
+            .collect_vec();
+
+        for (id, file_idx, len) in files {
+            let candidates = gaps
+                .iter()
+                .enumerate()
+                .take_while(|(_, (gap_idx, _))| *gap_idx < file_idx)
+                .filter(|(_, (_, gap_len))| *gap_len >= len);
+            let gap = match strategy {
+                Strategy::BestFit => {
+                    candidates.min_by_key(|(_, (gap_idx, gap_len))| (*gap_len, *gap_idx))
+                }
+                _ => candidates.min_by_key(|(_, (gap_idx, _))| *gap_idx),
+            }
+            .map(|(gap, _)| gap);
+
+            if let Some(gap) = gap {
+                let (gap_idx, gap_len) = gaps[gap];
+
+                self.blocks[gap_idx..gap_idx + len].fill(Some(id));
+                self.blocks[file_idx..file_idx + len].fill(None);
+                gaps[gap] = (gap_idx + len, gap_len - len);
+                Self::free(&mut gaps, file_idx, len);
+                gaps.retain(|(_, gap_len)| *gap_len > 0);
+                moves += 1;
+            }
+        }
+
+        moves
+    }
+
+    /// Adds a freed range to the sorted gaps, merging it with any gap it touches.
+    fn free(gaps: &mut Vec<(usize, usize)>, idx: usize, len: usize) {
+        let pos = gaps.partition_point(|&(gap_idx, _)| gap_idx < idx);
+        gaps.insert(pos, (idx, len));
+
+        if pos + 1 < gaps.len() && gaps[pos].0 + gaps[pos].1 == gaps[pos + 1].0 {
+            gaps[pos].1 += gaps[pos + 1].1;
+            gaps.remove(pos + 1);
+        }
+        if pos > 0 && gaps[pos - 1].0 + gaps[pos - 1].1 == gaps[pos].0 {
+            gaps[pos - 1].1 += gaps[pos].1;
+            gaps.remove(pos);
+        }
+    }
+
+    fn defragment(&mut self) -> usize {
+        let order = self.blocks.iter().flatten().copied().unique().collect_vec();
+        let counts = self.blocks.iter().flatten().counts();
+
+        let blocks = order
+            .iter()
+            .flat_map(|id| (0..counts[id]).map(move |_| Some(*id)))
+            .chain((0..self.blocks.iter().filter(|b| b.is_none()).count()).map(|_| None))
+            .collect_vec();
+
+        let positions = |blocks: &[Option<u64>]| {
+            blocks
+                .iter()
+                .enumerate()
+                .filter_map(|(idx, block)| block.map(|id| (id, idx)))
+                .into_group_map()
+        };
+        let before = positions(&self.blocks);
+        let after = positions(&blocks);
+
+        self.blocks = blocks;
+        order.iter().filter(|id| before[id] != after[id]).count()
+    }
+
+    /// Splits the disk in runs of identical blocks, as `(block, idx, len)`.
+    fn runs(&self) -> Vec<(Option<u64>, usize, usize)> {
+        let mut idx = 0;
+
+        self.blocks
+            .iter()
+            .chunk_by(|block| **block)
+            .into_iter()
+            .map(|(block, run)| {
+                let len = run.count();
+                idx += len;
+                (block, idx - len, len)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers, inputs};
+
+    #[test]
+    fn day_09_part_01_sample() {
+        let sample = "2333133121414131402";
+
+        assert_eq!(1_928, solve_1(sample));
+    }
+
+    #[test]
+    fn day_09_part_01_solution() {
+        let Some(input) = inputs::load(2024, 9) else {
+            return;
+        };
+        let input = input.trim();
+
+        assert_eq!(answers::expected(2024, 9, 1), solve_1(input).to_string());
+    }
+
+    #[test]
+    fn day_09_part_02_sample() {
+        let sample = "2333133121414131402";
+
+        assert_eq!(2_858, solve_2(sample));
+    }
+
+    #[test]
+    fn day_09_part_02_solution() {
+        let Some(input) = inputs::load(2024, 9) else {
+            return;
+        };
+        let input = input.trim();
+
+        assert_eq!(answers::expected(2024, 9, 2), solve_2(input).to_string());
+    }
+
+    #[test]
+    fn day_09_disk_map() {
+        let disk = DiskMap::new("2333133121414131402");
+
+        assert_eq!("00...111...2...333.44.5555.6666.777.888899", disk.render());
+        assert_eq!(Some("2333133121414131402".to_string()), disk.to_dense());
+
+        let block_wise = disk.compact(Strategy::BlockWise);
+        assert_eq!(
+            "0099811188827773336446555566..............",
+            block_wise.disk.render()
+        );
+        assert_eq!(1_928, block_wise.checksum);
+        assert_eq!(None, block_wise.disk.to_dense());
+
+        let first_fit = disk.compact(Strategy::FirstFit);
+        assert_eq!(
+            "00992111777.44.333....5555.6666.....8888..",
+            first_fit.disk.render()
+        );
+        assert_eq!(2_858, first_fit.checksum);
+        assert_eq!(4, first_fit.moves);
+    }
+
+    #[test]
+    fn day_09_alternative_strategies() {
+        let disk = DiskMap::new("13111");
+
+        let first_fit = disk.compact(Strategy::FirstFit);
+        let best_fit = disk.compact(Strategy::BestFit);
+        assert_eq!("021....", first_fit.disk.render());
+        assert_eq!("01...2.", best_fit.disk.render());
+        assert_eq!(2, best_fit.moves);
+
+        let defragmented = disk.compact(Strategy::Defragment);
+        assert_eq!("012....", defragmented.disk.render());
+        assert_eq!(2, defragmented.moves);
+        assert_eq!(Some("101014".to_string()), defragmented.disk.to_dense());
+    }
+
+    #[test]
+    fn day_09_empty_files() {
+        for dense in ["11011", "0213", "12001", "1102"] {
+            let disk = DiskMap::new(dense);
+
+            assert_eq!(Some(dense.to_string()), disk.to_dense());
+        }
+
+        let disk = DiskMap::new("11011");
+        assert_eq!("0..2", disk.render());
+
+        let first_fit = disk.compact(Strategy::FirstFit);
+        assert_eq!("02..", first_fit.disk.render());
+        assert_eq!(Some("100012".to_string()), first_fit.disk.to_dense());
+    }
+}
@@ -0,0 +1,412 @@
+use itertools::Itertools;
+use rustc_hash::FxHashMap;
+use std::ops::Add;
+
+pub fn solve_1(map: &[&str]) -> u32 {
+    Map::new(map).score(Scoring::SinglePath)
+}
+
+pub fn solve_2(map: &[&str]) -> u32 {
+    Map::new(map).score(Scoring::Rating)
+}
+
+#[derive(Debug)]
+pub struct Map {
+    tiles: FxHashMap<Position, u32>,
+}
+
+impl Map {
+    pub fn new(map: &[&str]) -> Self {
+        let tiles = map
+            .iter()
+            .enumerate()
+            .flat_map(|(y, line)| {
+                line.chars().enumerate().filter_map(move |(x, c)| {
+                    let position = Position {
+                        x: x as i32,
+                        y: y as i32,
+                    };
+                    c.to_digit(10).map(|height| (position, height))
+                })
+            })
+            .collect();
+
+        Map { tiles }
+    }
+
+    fn score(&self, scoring: Scoring) -> u32 {
+        let network = self.network(&StepRules::default());
+
+        network
+            .trailheads()
+            .iter()
+            .map(|trailhead| match scoring {
+                Scoring::SinglePath => trailhead.score,
+                Scoring::Rating => trailhead.rating as u32,
+            })
+            .sum()
+    }
+
+    /// Builds the DAG of all valid steps once, scoring and rating every trailhead on the way.
+    pub fn network(&self, rules: &StepRules) -> TrailNetwork {
+        assert_ne!(
+            0, rules.height_delta,
+            "trails need to change height to be acyclic"
+        );
+
+        let positions = self
+            .tiles
+            .keys()
+            .copied()
+            .sorted_by_key(|p| {
+                (
+                    self.tiles[p] as i64 * rules.height_delta.signum() as i64,
+                    *p,
+                )
+            })
+            .collect_vec();
+        let index: FxHashMap<Position, usize> = positions
+            .iter()
+            .enumerate()
+            .map(|(idx, &p)| (p, idx))
+            .collect();
+        let heights = positions.iter().map(|p| self.tiles[p]).collect_vec();
+
+        let successors = positions
+            .iter()
+            .zip(&heights)
+            .map(|(&position, &height)| {
+                if height == rules.end_height {
+                    return vec![];
+                }
+
+                rules
+                    .directions()
+                    .iter()
+                    .map(|&n_delta| n_delta + position)
+                    .filter_map(|n| index.get(&n).copied())
+                    .filter(|&n| heights[n] as i64 == height as i64 + rules.height_delta as i64)
+                    .collect()
+            })
+            .collect_vec();
+
+        let summits = (0..positions.len())
+            .filter(|&idx| heights[idx] == rules.end_height)
+            .collect_vec();
+        let summit_bits: FxHashMap<usize, usize> = summits
+            .iter()
+            .enumerate()
+            .map(|(bit, &idx)| (idx, bit))
+            .collect();
+        let nr_words = summits.len().div_ceil(64);
+
+        // Positions are sorted along the direction of the trails, so a single pass in reverse
+        // sees every successor before its predecessors
+        let mut reachable = vec![vec![0u64; nr_words]; positions.len()];
+        let mut ratings = vec![0u64; positions.len()];
+
+        for idx in (0..positions.len()).rev() {
+            if let Some(&bit) = summit_bits.get(&idx) {
+                reachable[idx][bit / 64] |= 1 << (bit % 64);
+                ratings[idx] = 1;
+                continue;
+            }
+
+            for &n in &successors[idx] {
+                let (before, after) = reachable.split_at_mut(n);
+                before[idx]
+                    .iter_mut()
+                    .zip(&after[0])
+                    .for_each(|(word, n_word)| *word |= n_word);
+                ratings[idx] += ratings[n];
+            }
+        }
+
+        let trailheads = (0..positions.len())
+            .filter(|&idx| heights[idx] == rules.start_height)
+            .map(|idx| Trailhead {
+                position: positions[idx],
+                score: reachable[idx].iter().map(|word| word.count_ones()).sum(),
+                rating: ratings[idx],
+            })
+            .sorted_by_key(|trailhead| trailhead.position)
+            .collect();
+
+        TrailNetwork {
+            positions,
+            index,
+            successors,
+            summits,
+            trailheads,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+enum Scoring {
+    SinglePath,
+    Rating,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct StepRules {
+    pub height_delta: i32,
+    pub diagonal: bool,
+    pub start_height: u32,
+    pub end_height: u32,
+}
+
+impl StepRules {
+    fn directions(&self) -> Vec<Position> {
+        let mut directions = vec![
+            Position { x: 0, y: 1 },
+            Position { x: 0, y: -1 },
+            Position { x: 1, y: 0 },
+            Position { x: -1, y: 0 },
+        ];
+
+        if self.diagonal {
+            directions.extend([
+                Position { x: 1, y: 1 },
+                Position { x: 1, y: -1 },
+                Position { x: -1, y: 1 },
+                Position { x: -1, y: -1 },
+            ]);
+        }
+
+        directions
+    }
+}
+
+impl Default for StepRules {
+    fn default() -> Self {
+        Self {
+            height_delta: 1,
+            diagonal: false,
+            start_height: 0,
+            end_height: 9,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Trailhead {
+    pub position: Position,
+    pub score: u32,
+    pub rating: u64,
+}
+
+#[derive(Debug)]
+pub struct TrailNetwork {
+    positions: Vec<Position>,
+    index: FxHashMap<Position, usize>,
+    successors: Vec<Vec<usize>>,
+    summits: Vec<usize>,
+    trailheads: Vec<Trailhead>,
+}
+
+impl TrailNetwork {
+    pub fn trailheads(&self) -> &[Trailhead] {
+        &self.trailheads
+    }
+
+    /// Counts, for every summit, the distinct trails from any trailhead ending on it.
+    pub fn summit_ratings(&self) -> FxHashMap<Position, u64> {
+        let mut ways = vec![0u64; self.positions.len()];
+
+        self.trailheads
+            .iter()
+            .for_each(|trailhead| ways[self.index[&trailhead.position]] = 1);
+
+        for idx in 0..self.positions.len() {
+            for &n in &self.successors[idx] {
+                ways[n] += ways[idx];
+            }
+        }
+
+        self.summits
+            .iter()
+            .map(|&idx| (self.positions[idx], ways[idx]))
+            .collect()
+    }
+
+    /// Lazily walks every trail starting at `trailhead`, yielding its positions in order.
+    pub fn trails(&self, trailhead: Position) -> Trails<'_> {
+        let stack = self
+            .trailheads
+            .iter()
+            .find(|t| t.position == trailhead)
+            .map(|t| vec![vec![self.index[&t.position]]])
+            .unwrap_or_default();
+
+        Trails {
+            network: self,
+            stack,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Trails<'a> {
+    network: &'a TrailNetwork,
+    stack: Vec<Vec<usize>>,
+}
+
+impl Iterator for Trails<'_> {
+    type Item = Vec<Position>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(trail) = self.stack.pop() {
+            let last = *trail.last().unwrap();
+
+            if self.network.summits.binary_search(&last).is_ok() {
+                return Some(
+                    trail
+                        .iter()
+                        .map(|&idx| self.network.positions[idx])
+                        .collect(),
+                );
+            }
+
+            self.network.successors[last].iter().rev().for_each(|&n| {
+                let mut next = trail.clone();
+                next.push(n);
+                self.stack.push(next);
+            });
+        }
+
+        None
+    }
+}
+
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct Position {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Add for Position {
+    type Output = Position;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Position {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::*;
+    use crate::{answers, inputs};
+
+    #[test]
+    fn day_10_part_01_sample() {
+        #[rustfmt::skip]
+        let sample = vec![
+            "89010123",
+            "78121874",
+            "87430965",
+            "96549874",
+            "45678903",
+            "32019012",
+            "01329801",
+            "10456732",
+        ];
+
+        assert_eq!(36, solve_1(&sample));
+    }
+
+    #[test]
+    fn day_10_part_01_solution() {
+        let Some(input) = inputs::load(2024, 10) else {
+            return;
+        };
+        let input = input.lines().collect_vec();
+
+        assert_eq!(answers::expected(2024, 10, 1), solve_1(&input).to_string());
+    }
+
+    #[test]
+    fn day_10_part_02_sample() {
+        #[rustfmt::skip]
+        let sample = vec![
+            "89010123",
+            "78121874",
+            "87430965",
+            "96549874",
+            "45678903",
+            "32019012",
+            "01329801",
+            "10456732",
+        ];
+
+        assert_eq!(81, solve_2(&sample));
+    }
+
+    #[test]
+    fn day_10_part_02_solution() {
+        let Some(input) = inputs::load(2024, 10) else {
+            return;
+        };
+        let input = input.lines().collect_vec();
+
+        assert_eq!(answers::expected(2024, 10, 2), solve_2(&input).to_string());
+    }
+
+    #[test]
+    fn day_10_trail_network() {
+        #[rustfmt::skip]
+        let sample = vec![
+            "89010123",
+            "78121874",
+            "87430965",
+            "96549874",
+            "45678903",
+            "32019012",
+            "01329801",
+            "10456732",
+        ];
+        let network = Map::new(&sample).network(&StepRules::default());
+
+        let trailhead = network.trailheads()[0];
+        let trails = network.trails(trailhead.position).collect_vec();
+        assert_eq!(trailhead.rating, trails.len() as u64);
+        assert!(trails.iter().all(|trail| trail.len() == 10));
+        assert_eq!(81, network.summit_ratings().values().sum::<u64>());
+    }
+
+    #[test]
+    fn day_10_step_rules() {
+        #[rustfmt::skip]
+        let sample = vec![
+            "0123",
+            "1234",
+            "2345",
+        ];
+        let map = Map::new(&sample);
+
+        let descending = map.network(&StepRules {
+            height_delta: -1,
+            start_height: 5,
+            end_height: 0,
+            ..StepRules::default()
+        });
+        let diagonal = map.network(&StepRules {
+            height_delta: 2,
+            diagonal: true,
+            start_height: 1,
+            end_height: 5,
+        });
+
+        assert_eq!(10, descending.trailheads()[0].rating);
+        assert_eq!(1, descending.trailheads()[0].score);
+        assert_eq!(
+            vec![0, 1],
+            diagonal.trailheads().iter().map(|t| t.rating).collect_vec()
+        );
+    }
+}
@@ -0,0 +1,367 @@
+use itertools::Itertools;
+use rustc_hash::FxHashMap;
+use std::fmt;
+use std::fmt::{Debug, Display, Formatter};
+
+pub fn solve_1(stones: &str) -> u64 {
+    solve(stones, 25)
+}
+
+pub fn solve_2(stones: &str) -> u64 {
+    solve(stones, 75)
+}
+
+fn solve(stones: &str, iterations: u32) -> u64 {
+    let mut engine: Engine<u64> = Engine::new(stones, Rules::default());
+    engine.totals(iterations).pop().unwrap()
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Rule {
+    /// Replaces a stone engraved with `from` by one engraved with `to`.
+    Replace { from: u64, to: u64 },
+    /// Splits a stone with an even number of digits into its left and right half.
+    SplitEvenDigits,
+    /// Multiplies the engraving of any stone by `factor`.
+    Multiply(u64),
+}
+
+impl Rule {
+    fn apply(&self, stone: u64) -> Option<(u64, Option<u64>)> {
+        match *self {
+            Rule::Replace { from, to } => (stone == from).then_some((to, None)),
+            Rule::SplitEvenDigits => nr_digits(stone).is_multiple_of(2).then(|| {
+                let (left, right) = split_stone(stone);
+                (left, Some(right))
+            }),
+            Rule::Multiply(factor) => Some((
+                stone
+                    .checked_mul(factor)
+                    .expect("engraving does not fit in a u64"),
+                None,
+            )),
+        }
+    }
+}
+
+/// Rules are tried in order, the first one that applies transforms the stone.
+/// Stones matching no rule at all are left as they are.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Rules(pub Vec<Rule>);
+
+impl Rules {
+    fn apply(&self, stone: u64) -> (u64, Option<u64>) {
+        self.0
+            .iter()
+            .find_map(|rule| rule.apply(stone))
+            .unwrap_or((stone, None))
+    }
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Self(vec![
+            Rule::Replace { from: 0, to: 1 },
+            Rule::SplitEvenDigits,
+            Rule::Multiply(2024),
+        ])
+    }
+}
+
+pub trait Counter: Clone + Debug {
+    fn zero() -> Self;
+    fn one() -> Self;
+    fn add(&mut self, other: &Self);
+}
+
+impl Counter for u64 {
+    fn zero() -> Self {
+        0
+    }
+
+    fn one() -> Self {
+        1
+    }
+
+    fn add(&mut self, other: &Self) {
+        *self = self
+            .checked_add(*other)
+            .expect("stone count overflowed, use a BigCount instead");
+    }
+}
+
+impl Counter for u128 {
+    fn zero() -> Self {
+        0
+    }
+
+    fn one() -> Self {
+        1
+    }
+
+    fn add(&mut self, other: &Self) {
+        *self = self
+            .checked_add(*other)
+            .expect("stone count overflowed, use a BigCount instead");
+    }
+}
+
+/// Arbitrary-precision stone count, stored as little-endian base 2^32 limbs.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+pub struct BigCount {
+    limbs: Vec<u32>,
+}
+
+impl Counter for BigCount {
+    fn zero() -> Self {
+        Self::default()
+    }
+
+    fn one() -> Self {
+        Self { limbs: vec![1] }
+    }
+
+    fn add(&mut self, other: &Self) {
+        if self.limbs.len() < other.limbs.len() {
+            self.limbs.resize(other.limbs.len(), 0);
+        }
+
+        let mut carry = 0u64;
+        for (idx, limb) in self.limbs.iter_mut().enumerate() {
+            let sum = *limb as u64 + *other.limbs.get(idx).unwrap_or(&0) as u64 + carry;
+            *limb = sum as u32;
+            carry = sum >> 32;
+        }
+
+        if carry > 0 {
+            self.limbs.push(carry as u32);
+        }
+    }
+}
+
+impl Display for BigCount {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        const CHUNK: u64 = 1_000_000_000;
+
+        // Repeatedly divide by 10^9, collecting the remainders as groups of 9 decimal digits
+        let mut limbs = self.limbs.clone();
+        let mut chunks = vec![];
+
+        while limbs.iter().any(|&limb| limb > 0) {
+            let mut remainder = 0u64;
+            for limb in limbs.iter_mut().rev() {
+                let current = (remainder << 32) | *limb as u64;
+                *limb = (current / CHUNK) as u32;
+                remainder = current % CHUNK;
+            }
+            chunks.push(remainder);
+        }
+
+        match chunks.split_last() {
+            None => write!(f, "0"),
+            Some((first, rest)) => {
+                write!(f, "{first}")?;
+                rest.iter()
+                    .rev()
+                    .try_for_each(|chunk| write!(f, "{chunk:0>9}"))
+            }
+        }
+    }
+}
+
+/// Tracks how many stones carry each engraving, as order does not matter for counting.
+#[derive(Debug, Clone)]
+pub struct Engine<C: Counter = u64> {
+    rules: Rules,
+    stones: FxHashMap<u64, C>,
+}
+
+impl<C: Counter> Engine<C> {
+    pub fn new(stones: &str, rules: Rules) -> Self {
+        let mut engine = Self {
+            rules,
+            stones: FxHashMap::default(),
+        };
+
+        stones
+            .split_whitespace()
+            .map(|s| s.parse::<u64>().unwrap())
+            .for_each(|stone| engine.insert(stone, &C::one()));
+
+        engine
+    }
+
+    fn insert(&mut self, stone: u64, count: &C) {
+        self.stones.entry(stone).or_insert_with(C::zero).add(count);
+    }
+
+    pub fn stones(&self) -> &FxHashMap<u64, C> {
+        &self.stones
+    }
+
+    pub fn total(&self) -> C {
+        self.stones.values().fold(C::zero(), |mut total, count| {
+            total.add(count);
+            total
+        })
+    }
+
+    pub fn blink(&mut self) {
+        let stones = std::mem::take(&mut self.stones);
+
+        for (stone, count) in stones {
+            let (left, right) = self.rules.apply(stone);
+
+            self.insert(left, &count);
+            if let Some(right) = right {
+                self.insert(right, &count);
+            }
+        }
+    }
+
+    /// Blinks `blinks` times, returning the total number of stones after every blink,
+    /// starting with the current total before blinking at all.
+    pub fn totals(&mut self, blinks: u32) -> Vec<C> {
+        let mut totals = vec![self.total()];
+
+        for _ in 0..blinks {
+            self.blink();
+            totals.push(self.total());
+        }
+
+        totals
+    }
+
+    /// Follows the descendants of every current engraving on its own,
+    /// returning how many stones each one has grown into after every blink.
+    pub fn lineage(&self, blinks: u32) -> Vec<(u64, Vec<C>)> {
+        self.stones
+            .iter()
+            .sorted_by_key(|(&stone, _)| stone)
+            .map(|(&stone, count)| {
+                let mut engine = Self {
+                    rules: self.rules.clone(),
+                    stones: FxHashMap::default(),
+                };
+                engine.insert(stone, count);
+
+                (stone, engine.totals(blinks))
+            })
+            .collect()
+    }
+}
+
+fn nr_digits(n: u64) -> u32 {
+    match n {
+        0..=9 => 1,
+        10..=99 => 2,
+        100..=999 => 3,
+        1_000..=9_999 => 4,
+        10_000..=99_999 => 5,
+        100_000..=999_999 => 6,
+        1_000_000..=9_999_999 => 7,
+        10_000_000..=99_999_999 => 8,
+        100_000_000..=999_999_999 => 9,
+        1_000_000_000..=9_999_999_999 => 10,
+        10_000_000_000..=99_999_999_999 => 11,
+        100_000_000_000..=999_999_999_999 => 12,
+        1_000_000_000_000..=9_999_999_999_999 => 13,
+        10_000_000_000_000..=99_999_999_999_999 => 14,
+        100_000_000_000_000..=999_999_999_999_999 => 15,
+        1_000_000_000_000_000..=9_999_999_999_999_999 => 16,
+        10_000_000_000_000_000..=99_999_999_999_999_999 => 17,
+        100_000_000_000_000_000..=999_999_999_999_999_999 => 18,
+        1_000_000_000_000_000_000..=9_999_999_999_999_999_999 => 19,
+        10_000_000_000_000_000_000..=u64::MAX => 20,
+    }
+}
+
+fn split_stone(stone: u64) -> (u64, u64) {
+    let nr_digits = nr_digits(stone);
+    let zeroes = 10u64.pow(nr_digits / 2);
+
+    (stone / zeroes, stone % zeroes)
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::*;
+    use crate::{answers, inputs};
+
+    #[test]
+    fn day_11_part_01_sample() {
+        let sample = "125 17";
+
+        assert_eq!(55_312, solve_1(sample));
+    }
+
+    #[test]
+    fn day_11_part_01_solution() {
+        let Some(input) = inputs::load(2024, 11) else {
+            return;
+        };
+        let input = input.trim();
+
+        assert_eq!(answers::expected(2024, 11, 1), solve_1(input).to_string());
+    }
+
+    #[test]
+    fn day_11_part_02_sample() {
+        // No sample input provided
+    }
+
+    #[test]
+    fn day_11_part_02_solution() {
+        let Some(input) = inputs::load(2024, 11) else {
+            return;
+        };
+        let input = input.trim();
+
+        assert_eq!(answers::expected(2024, 11, 2), solve_2(input).to_string());
+    }
+
+    #[test]
+    fn day_11_intermediate_blinks() {
+        let mut engine: Engine<u64> = Engine::new("125 17", Rules::default());
+
+        assert_eq!(vec![2, 3, 4, 5, 9, 13, 22], engine.totals(6));
+
+        let lineage = Engine::<u64>::new("125 17", Rules::default()).lineage(2);
+        assert_eq!(vec![(17, vec![1, 2, 2]), (125, vec![1, 1, 2])], lineage);
+    }
+
+    #[test]
+    fn day_11_custom_rules() {
+        let rules = Rules(vec![Rule::SplitEvenDigits, Rule::Multiply(11)]);
+        let mut engine: Engine<u64> = Engine::new("0 12", rules);
+
+        engine.blink();
+        assert_eq!(
+            vec![(0, 1), (1, 1), (2, 1)],
+            engine
+                .stones()
+                .iter()
+                .map(|(&s, &c)| (s, c))
+                .sorted()
+                .collect_vec()
+        );
+    }
+
+    #[test]
+    fn day_11_big_count() {
+        let mut small: Engine<u128> = Engine::new("125 17", Rules::default());
+        let mut big: Engine<BigCount> = Engine::new("125 17", Rules::default());
+
+        let small = small.totals(75).pop().unwrap();
+        let big = big.totals(75).pop().unwrap();
+
+        assert_eq!(small.to_string(), big.to_string());
+        assert_eq!("0", BigCount::zero().to_string());
+
+        let mut huge = BigCount::one();
+        (0..100).for_each(|_| huge.add(&huge.clone()));
+        assert_eq!("1267650600228229401496703205376", huge.to_string());
+    }
+}
@@ -241,6 +241,7 @@ mod tests {
     use itertools::Itertools;
 
     use super::*;
+    use crate::{answers, inputs};
 
     #[test]
     fn day_12_part_01_sample() {
@@ -280,11 +281,12 @@ mod tests {
 
     #[test]
     fn day_12_part_01_solution() {
-        let input = include_str!("../../inputs/day_12.txt")
-            .lines()
-            .collect_vec();
+        let Some(input) = inputs::load(2024, 12) else {
+            return;
+        };
+        let input = input.lines().collect_vec();
 
-        assert_eq!(1_437_300, solve_1(&input));
+        assert_eq!(answers::expected(2024, 12, 1), solve_1(&input).to_string());
     }
 
     #[test]
@@ -344,10 +346,11 @@ mod tests {
 
     #[test]
     fn day_12_part_02_solution() {
-        let input = include_str!("../../inputs/day_12.txt")
-            .lines()
-            .collect_vec();
+        let Some(input) = inputs::load(2024, 12) else {
+            return;
+        };
+        let input = input.lines().collect_vec();
 
-        assert_eq!(849_332, solve_2(&input));
+        assert_eq!(answers::expected(2024, 12, 2), solve_2(&input).to_string());
     }
 }
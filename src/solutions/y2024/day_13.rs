@@ -0,0 +1,404 @@
+use crate::parallel;
+use crate::util::linear;
+use crate::util::linear::Solutions;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+pub fn solve_1(machines: &str) -> i64 {
+    solve(machines, None, Some(100))
+}
+
+pub fn solve_2(machines: &str) -> i64 {
+    solve(machines, Some(10_000_000_000_000), None)
+}
+
+fn solve(machines: &str, addition: Option<i64>, max_nr_pushes: Option<i64>) -> i64 {
+    let machines = machines.split("\n\n").collect::<Vec<_>>();
+
+    parallel::fold(
+        &machines,
+        || 0,
+        |tokens, machine| {
+            let machine = Machine::new(machine, addition);
+            let min_tokens = machine
+                .min_tokens(&COSTS, max_nr_pushes)
+                .expect("two buttons can always be solved");
+            tokens + min_tokens.unwrap_or(0)
+        },
+        |a, b| a + b,
+    )
+}
+
+/// Tokens it costs to push button A and B respectively.
+pub const COSTS: [i64; 2] = [3, 1];
+
+/// Most combinations of pushes of the extra buttons tried before giving up.
+const MAX_EXTRA_PUSHES: i64 = 1_000_000;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum TokensError {
+    /// The number of pushes or tokens no longer fits in an integer.
+    Overflow,
+    /// The extra buttons can be pushed in too many ways to try them all.
+    TooManyPushes,
+}
+
+impl Display for TokensError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TokensError::Overflow => write!(f, "number of pushes overflows"),
+            TokensError::TooManyPushes => write!(
+                f,
+                "more than {MAX_EXTRA_PUSHES} ways to push the extra buttons"
+            ),
+        }
+    }
+}
+
+impl Error for TokensError {}
+
+impl From<linear::Overflow> for TokensError {
+    fn from(_: linear::Overflow) -> Self {
+        TokensError::Overflow
+    }
+}
+
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct Machine {
+    buttons: Vec<Position>,
+    prize: Position,
+}
+
+impl Machine {
+    /// Parses a machine with any number of buttons, each on its own line before the prize.
+    pub fn new(machine: &str, addition: Option<i64>) -> Self {
+        let lines = machine.lines().map(str::trim).collect::<Vec<_>>();
+        let (prize, buttons) = lines.split_last().unwrap();
+
+        let buttons = buttons
+            .iter()
+            .map(|button| Position::from_str(button, &BUTTON_RE))
+            .collect();
+        let mut prize = Position::from_str(prize, &PRIZE_RE);
+
+        if let Some(addition) = addition {
+            prize.x += addition;
+            prize.y += addition;
+        }
+
+        Self { buttons, prize }
+    }
+
+    /// Cheapest way to win the prize, given the cost of pushing each button.
+    ///
+    /// With more than two buttons, all push counts of the extra buttons are tried,
+    /// bounded by how often they fit in the prize, before solving for the last two.
+    /// Rather than running for ages, this gives up if there are too many combinations.
+    pub fn min_tokens(
+        &self,
+        costs: &[i64],
+        max_nr_pushes: Option<i64>,
+    ) -> Result<Option<i64>, TokensError> {
+        assert_eq!(self.buttons.len(), costs.len(), "every button needs a cost");
+
+        let max_nr_pushes = max_nr_pushes.unwrap_or(i64::MAX);
+
+        let extra_buttons = &self.buttons[..self.buttons.len().saturating_sub(2)];
+        extra_buttons
+            .iter()
+            .try_fold(1, |combinations: i64, button| {
+                combinations
+                    .checked_mul(Self::fits(*button, self.prize).min(max_nr_pushes) + 1)
+                    .filter(|&combinations| combinations <= MAX_EXTRA_PUSHES)
+                    .ok_or(TokensError::TooManyPushes)
+            })?;
+
+        self.min_tokens_helper(&self.buttons, costs, self.prize, max_nr_pushes)
+    }
+
+    /// How often the button can be pushed before passing the prize.
+    fn fits(button: Position, prize: Position) -> i64 {
+        // A button moving the claw nowhere only adds to the cost, so it is never pushed
+        [(prize.x, button.x), (prize.y, button.y)]
+            .into_iter()
+            .filter(|&(_, delta)| delta > 0)
+            .map(|(total, delta)| total / delta)
+            .min()
+            .unwrap_or(0)
+    }
+
+    fn min_tokens_helper(
+        &self,
+        buttons: &[Position],
+        costs: &[i64],
+        prize: Position,
+        max_nr_pushes: i64,
+    ) -> Result<Option<i64>, TokensError> {
+        match buttons.len() {
+            0 => Ok((prize == Position { x: 0, y: 0 }).then_some(0)),
+            1 => Self::min_tokens_pair(
+                buttons[0],
+                Position { x: 0, y: 0 },
+                (costs[0], 0),
+                prize,
+                max_nr_pushes,
+            ),
+            2 => Self::min_tokens_pair(
+                buttons[0],
+                buttons[1],
+                (costs[0], costs[1]),
+                prize,
+                max_nr_pushes,
+            ),
+            _ => {
+                let (button, buttons) = buttons.split_last().unwrap();
+                let (cost, costs) = costs.split_last().unwrap();
+
+                let mut min_tokens = None;
+                for pushes in 0..=Self::fits(*button, prize).min(max_nr_pushes) {
+                    let prize = Position {
+                        x: prize.x - pushes * button.x,
+                        y: prize.y - pushes * button.y,
+                    };
+                    let Some(tokens) =
+                        self.min_tokens_helper(buttons, costs, prize, max_nr_pushes)?
+                    else {
+                        continue;
+                    };
+                    let tokens = pushes
+                        .checked_mul(*cost)
+                        .and_then(|extra| tokens.checked_add(extra))
+                        .ok_or(TokensError::Overflow)?;
+                    min_tokens = Some(min_tokens.map_or(tokens, |min: i64| min.min(tokens)));
+                }
+
+                Ok(min_tokens)
+            }
+        }
+    }
+
+    fn min_tokens_pair(
+        button_a: Position,
+        button_b: Position,
+        (cost_a, cost_b): (i64, i64),
+        prize: Position,
+        max_nr_pushes: i64,
+    ) -> Result<Option<i64>, TokensError> {
+        // The system is modeled by the following set of equations:
+        //
+        // 1. button_a.x * pushes_a + button_b.x * pushes_b = price.x
+        // 2. button_a.y * pushes_a + button_b.y * pushes_b = price.y
+        // 3. pushes_a, pushes_b ∈ ℕ
+        // 4. cost = cost_a * pushes_a + cost_b * pushes_b
+        //
+        // Usually 1 and 2 have a single solution, which needs to be in the set of Natural numbers
+        // When both buttons are collinear, all integer solutions lie on a line instead
+        // As the cost is linear, it is minimal at one of the ends of its part within bounds
+
+        let pushes = 0..=max_nr_pushes as i128;
+        let cost = |(pushes_a, pushes_b): (i128, i128)| {
+            (cost_a as i128)
+                .checked_mul(pushes_a)
+                .zip((cost_b as i128).checked_mul(pushes_b))
+                .and_then(|(cost_a, cost_b)| cost_a.checked_add(cost_b))
+                .and_then(|cost| i64::try_from(cost).ok())
+                .ok_or(TokensError::Overflow)
+        };
+
+        let solutions = linear::solve_2x2(
+            [
+                [button_a.x as i128, button_b.x as i128],
+                [button_a.y as i128, button_b.y as i128],
+            ],
+            [prize.x as i128, prize.y as i128],
+        )?;
+
+        match solutions {
+            Solutions::None => Ok(None),
+            Solutions::Unique(pushes_a, pushes_b) => {
+                if pushes.contains(&pushes_a) && pushes.contains(&pushes_b) {
+                    cost((pushes_a, pushes_b)).map(Some)
+                } else {
+                    Ok(None)
+                }
+            }
+            Solutions::Line(line) => {
+                let Some(range) = line.t_range(pushes.clone(), pushes) else {
+                    return Ok(None);
+                };
+
+                let cost_start = cost(line.at(*range.start())?)?;
+                let cost_end = cost(line.at(*range.end())?)?;
+                Ok(Some(cost_start.min(cost_end)))
+            }
+            Solutions::Any => Ok(Some(0)),
+        }
+    }
+}
+
+lazy_static! {
+    static ref BUTTON_RE: Regex =
+        Regex::new(r"^Button [A-Z]: X\+(?<x>\d+), Y\+(?<y>\d+)$").unwrap();
+    static ref PRIZE_RE: Regex = Regex::new(r"^Prize: X=(?<x>\d+), Y=(?<y>\d+)$").unwrap();
+}
+
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct Position {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Position {
+    fn from_str(position: &str, regex: &Regex) -> Self {
+        let position = regex.captures(position).unwrap();
+        let x = position.name("x").unwrap().as_str().parse::<i64>().unwrap();
+        let y = position.name("y").unwrap().as_str().parse::<i64>().unwrap();
+        Self { x, y }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers, inputs};
+
+    #[test]
+    fn day_13_part_01_sample() {
+        let sample = "\
+                Button A: X+94, Y+34\n\
+                Button B: X+22, Y+67\n\
+                Prize: X=8400, Y=5400\n\
+                \n\
+                Button A: X+26, Y+66\n\
+                Button B: X+67, Y+21\n\
+                Prize: X=12748, Y=12176\n\
+                \n\
+                Button A: X+17, Y+86\n\
+                Button B: X+84, Y+37\n\
+                Prize: X=7870, Y=6450\n\
+                \n\
+                Button A: X+69, Y+23\n\
+                Button B: X+27, Y+71\n\
+                Prize: X=18641, Y=10279\
+            ";
+
+        assert_eq!(480, solve_1(sample));
+    }
+
+    #[test]
+    fn day_13_part_01_solution() {
+        let Some(input) = inputs::load(2024, 13) else {
+            return;
+        };
+        let input = input.trim();
+
+        assert_eq!(answers::expected(2024, 13, 1), solve_1(input).to_string());
+    }
+
+    #[test]
+    fn day_13_part_02_sample() {
+        // No sample solution provided
+    }
+
+    #[test]
+    fn day_13_part_02_solution() {
+        let Some(input) = inputs::load(2024, 13) else {
+            return;
+        };
+        let input = input.trim();
+
+        assert_eq!(answers::expected(2024, 13, 2), solve_2(input).to_string());
+    }
+
+    #[test]
+    fn day_13_collinear_buttons() {
+        let machine = Machine::new(
+            "\
+                Button A: X+2, Y+2\n\
+                Button B: X+4, Y+4\n\
+                Prize: X=10, Y=10\
+            ",
+            None,
+        );
+        let unreachable = Machine::new(
+            "\
+                Button A: X+2, Y+2\n\
+                Button B: X+4, Y+4\n\
+                Prize: X=10, Y=12\
+            ",
+            None,
+        );
+
+        assert_eq!(Ok(Some(5)), machine.min_tokens(&COSTS, Some(100)));
+        assert_eq!(Ok(None), machine.min_tokens(&COSTS, Some(1)));
+        assert_eq!(Ok(None), unreachable.min_tokens(&COSTS, None));
+    }
+
+    #[test]
+    fn day_13_more_buttons() {
+        let machine = Machine::new(
+            "\
+                Button A: X+1, Y+0\n\
+                Button B: X+0, Y+1\n\
+                Button C: X+1, Y+1\n\
+                Prize: X=5, Y=7\
+            ",
+            None,
+        );
+
+        assert_eq!(Ok(Some(7)), machine.min_tokens(&[3, 1, 1], None));
+        assert_eq!(Ok(Some(22)), machine.min_tokens(&[3, 1, 10], None));
+
+        let far_away = Machine::new(
+            "\
+                Button A: X+1, Y+0\n\
+                Button B: X+0, Y+1\n\
+                Button C: X+1, Y+1\n\
+                Prize: X=5, Y=7\
+            ",
+            Some(10_000_000_000_000),
+        );
+
+        assert_eq!(
+            Err(TokensError::TooManyPushes),
+            far_away.min_tokens(&[3, 1, 1], None)
+        );
+        assert_eq!(Ok(None), far_away.min_tokens(&[3, 1, 1], Some(5)));
+    }
+
+    #[test]
+    fn day_13_overflow() {
+        let machine = Machine::new(
+            "\
+                Button A: X+1, Y+1\n\
+                Button B: X+1, Y+2\n\
+                Prize: X=2, Y=2\
+            ",
+            None,
+        );
+
+        assert_eq!(Ok(Some(6)), machine.min_tokens(&COSTS, None));
+        assert_eq!(
+            Err(TokensError::Overflow),
+            machine.min_tokens(&[i64::MAX, 1], None)
+        );
+    }
+
+    #[test]
+    fn day_13_zero_button() {
+        let machine = Machine::new(
+            "\
+                Button A: X+1, Y+0\n\
+                Button B: X+0, Y+1\n\
+                Button C: X+0, Y+0\n\
+                Prize: X=5, Y=7\
+            ",
+            None,
+        );
+
+        assert_eq!(Ok(Some(22)), machine.min_tokens(&[3, 1, 1], None));
+        assert_eq!(Ok(Some(22)), machine.min_tokens(&[3, 1, 0], Some(100)));
+    }
+}
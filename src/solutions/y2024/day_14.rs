@@ -1,10 +1,16 @@
+use crate::util;
 use itertools::Itertools;
 use lazy_static::lazy_static;
 use regex::Regex;
 use rustc_hash::FxHashSet;
-use std::error::Error;
-use std::fs::File;
-use std::io::{BufWriter, Write};
+use std::io;
+use std::io::BufRead;
+#[cfg(not(target_arch = "wasm32"))]
+use std::{
+    error::Error,
+    fs::File,
+    io::{BufWriter, Write},
+};
 
 pub fn solve_1(robots: &[&str], room: &Room) -> u32 {
     robots
@@ -20,6 +26,24 @@ pub fn solve_1(robots: &[&str], room: &Room) -> u32 {
         .unwrap()
 }
 
+/// Same as [`solve_1`], reading the robots line by line and only counting them per quadrant.
+///
+/// Part 2 replays all robots for thousands of seconds, so it has no such counterpart.
+pub fn solve_1_from(robots: impl BufRead, room: &Room) -> io::Result<u32> {
+    let mut quadrants = [0; 4];
+    util::for_each_line(robots, |robot| {
+        if let Some(quadrant) = room.quadrant(Robot::new(robot).walk(100, room).position) {
+            quadrants[quadrant as usize - 1] += 1;
+        }
+    })?;
+
+    Ok(quadrants
+        .into_iter()
+        .filter(|&count| count > 0)
+        .reduce(|a, b| a * b)
+        .unwrap())
+}
+
 pub fn solve_2(robots: &[&str], room: &Room) -> i32 {
     let robots = robots.iter().map(|robot| Robot::new(robot)).collect_vec();
 
@@ -124,6 +148,7 @@ mod tests {
     use itertools::Itertools;
 
     use super::*;
+    use crate::{answers, inputs};
 
     #[test]
     fn day_14_part_01_sample() {
@@ -151,15 +176,19 @@ mod tests {
 
     #[test]
     fn day_14_part_01_solution() {
-        let input = include_str!("../../inputs/day_14.txt")
-            .lines()
-            .collect_vec();
+        let Some(input) = inputs::load(2024, 14) else {
+            return;
+        };
+        let input = input.lines().collect_vec();
         let room = Room {
             width: 101,
             height: 103,
         };
 
-        assert_eq!(229_421_808, solve_1(&input, &room));
+        assert_eq!(
+            answers::expected(2024, 14, 1),
+            solve_1(&input, &room).to_string()
+        );
     }
 
     #[test]
@@ -169,15 +198,33 @@ mod tests {
 
     #[test]
     fn day_14_part_02_solution() {
-        let input = include_str!("../../inputs/day_14.txt")
-            .lines()
-            .collect_vec();
+        let Some(input) = inputs::load(2024, 14) else {
+            return;
+        };
+        let input = input.lines().collect_vec();
         let room = Room {
             width: 101,
             height: 103,
         };
 
-        assert_eq!(6_577, solve_2(&input, &room));
+        assert_eq!(
+            answers::expected(2024, 14, 2),
+            solve_2(&input, &room).to_string()
+        );
+    }
+
+    #[test]
+    fn day_14_streamed() {
+        let sample = "\
+            p=0,4 v=3,-3\np=6,3 v=-1,-3\np=10,3 v=-1,2\np=2,0 v=2,-1\n\
+            p=0,0 v=1,3\np=3,0 v=-2,-2\np=7,6 v=-1,-3\np=3,0 v=-1,-2\n\
+            p=9,3 v=2,3\np=7,3 v=-1,2\np=2,4 v=2,-3\np=9,5 v=-3,-3\n";
+        let room = Room {
+            width: 11,
+            height: 7,
+        };
+
+        assert_eq!(12, solve_1_from(sample.as_bytes(), &room).unwrap());
     }
 }
 
@@ -185,6 +232,7 @@ mod tests {
 // Iterates and prints rooms to a file.
 // Grepping for continuous robots will visually show the answer.
 #[allow(dead_code)]
+#[cfg(not(target_arch = "wasm32"))]
 fn print_to_file(robots: &[Robot], room: &Room) -> Result<(), Box<dyn Error>> {
     let mut robots = robots.to_vec();
     let file = File::create("rooms.txt")?;
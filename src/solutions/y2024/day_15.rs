@@ -309,6 +309,7 @@ impl Coordinate {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{answers, inputs};
 
     #[test]
     fn day_15_part_01_sample() {
@@ -354,9 +355,12 @@ mod tests {
 
     #[test]
     fn day_15_part_01_solution() {
-        let input = include_str!("../../inputs/day_15.txt").trim();
+        let Some(input) = inputs::load(2024, 15) else {
+            return;
+        };
+        let input = input.trim();
 
-        assert_eq!(1_568_399, solve_1(input));
+        assert_eq!(answers::expected(2024, 15, 1), solve_1(input).to_string());
     }
 
     #[test]
@@ -390,8 +394,11 @@ mod tests {
 
     #[test]
     fn day_15_part_02_solution() {
-        let input = include_str!("../../inputs/day_15.txt").trim();
+        let Some(input) = inputs::load(2024, 15) else {
+            return;
+        };
+        let input = input.trim();
 
-        assert_eq!(1_575_877, solve_2(input));
+        assert_eq!(answers::expected(2024, 15, 2), solve_2(input).to_string());
     }
 }
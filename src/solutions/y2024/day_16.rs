@@ -1,51 +1,50 @@
 use crate::util::graph::{Dijkstra, Graph};
 use itertools::Itertools;
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 pub fn solve_1(maze: &[&str]) -> u32 {
     solve(maze).shortest_distance
 }
 
 pub fn solve_2(maze: &[&str]) -> usize {
-    solve(maze).shortest_tiles
+    solve(maze).shortest_tiles.len()
 }
 
 fn solve(maze: &[&str]) -> SolvedMaze {
-    let maze = Maze::new(maze);
-    let dijkstra = maze.dijkstra(&maze.start);
-
-    let shortest_distance = maze
-        .ends
-        .iter()
-        .map(|end| dijkstra.distance(end))
-        .min()
-        .unwrap();
-    let shortest_tiles = maze
-        .ends
-        .iter()
-        .filter(|end| dijkstra.distance(end) == shortest_distance)
-        .map(|end| dijkstra.shortest_paths(end))
-        .flat_map(|paths| paths.into_iter())
-        .flat_map(|path| path.into_iter())
-        .map(|node| node.coordinate)
-        .unique()
-        .count();
-
-    SolvedMaze {
-        shortest_distance,
-        shortest_tiles,
+    Maze::new(maze, &Rules::default()).solve().unwrap()
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Rules {
+    pub step_cost: u32,
+    /// Cost of a quarter turn on the spot, `None` disallows them.
+    pub turn_cost: Option<u32>,
+    /// Cost of turning around on the spot, `None` disallows it.
+    pub u_turn_cost: Option<u32>,
+    pub start_orientation: Orientation,
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Self {
+            step_cost: 1,
+            turn_cost: Some(1_000),
+            u_turn_cost: None,
+            start_orientation: Orientation::East,
+        }
     }
 }
 
 #[derive(Debug)]
-struct Maze {
-    pub start: Node,
-    pub ends: Vec<Node>,
+pub struct Maze {
+    start: Node,
+    ends: Vec<Node>,
     graph: Graph<Node, u32>,
+    lines: Vec<String>,
 }
 
 impl Maze {
-    pub fn new(maze: &[&str]) -> Self {
+    pub fn new(maze: &[&str], rules: &Rules) -> Self {
         let tiles: FxHashMap<Coordinate, char> = maze
             .iter()
             .enumerate()
@@ -64,7 +63,7 @@ impl Maze {
             .unwrap();
         let start = Node {
             coordinate: start,
-            orientation: Orientation::East,
+            orientation: rules.start_orientation,
         };
 
         let end = tiles
@@ -72,58 +71,108 @@ impl Maze {
             .find(|(_, c)| **c == 'E')
             .map(|(coord, _)| *coord)
             .unwrap();
-        let ends = [
-            Orientation::North,
-            Orientation::East,
-            Orientation::South,
-            Orientation::West,
-        ]
-        .map(|orientation| Node {
-            coordinate: end,
-            orientation,
-        })
-        .to_vec();
+        let ends = Orientation::ALL
+            .map(|orientation| Node {
+                coordinate: end,
+                orientation,
+            })
+            .to_vec();
 
         let mut graph = Graph::default();
         tiles
             .keys()
             .flat_map(|&coordinate| {
-                [
-                    Orientation::North,
-                    Orientation::East,
-                    Orientation::South,
-                    Orientation::West,
-                ]
-                .map(move |orientation| Node {
+                Orientation::ALL.map(move |orientation| Node {
                     coordinate,
                     orientation,
                 })
             })
             .flat_map(|node| {
-                node.neighbours()
+                node.neighbours(rules)
                     .into_iter()
+                    .filter(|(neighbour, _)| tiles.contains_key(&neighbour.coordinate))
                     .map(move |(neighbour, weight)| (node, neighbour, weight))
             })
             .for_each(|(node, neighbour, weight)| graph.add_edge(&node, &neighbour, &weight));
 
-        Self { start, ends, graph }
+        let lines = maze.iter().map(|line| line.to_string()).collect();
+
+        Self {
+            start,
+            ends,
+            graph,
+            lines,
+        }
     }
 
-    pub fn dijkstra(&self, start: &Node) -> Dijkstra<Node, u32> {
+    fn dijkstra(&self, start: &Node) -> Dijkstra<Node, u32> {
         self.graph.dijkstra(start)
     }
+
+    /// Finds all best paths from start to end, `None` if the end cannot be reached.
+    pub fn solve(&self) -> Option<SolvedMaze> {
+        let dijkstra = self.dijkstra(&self.start);
+
+        let shortest_distance = self
+            .ends
+            .iter()
+            .filter_map(|end| dijkstra.distance(end))
+            .min()?;
+        let paths = self
+            .ends
+            .iter()
+            .filter(|end| dijkstra.distance(end) == Some(shortest_distance))
+            .flat_map(|end| dijkstra.shortest_paths(end))
+            .map(|path| {
+                path.into_iter()
+                    .map(|node| node.coordinate)
+                    .dedup()
+                    .collect_vec()
+            })
+            .unique()
+            .collect_vec();
+        let shortest_tiles = paths.iter().flatten().copied().collect();
+
+        Some(SolvedMaze {
+            shortest_distance,
+            shortest_tiles,
+            paths,
+        })
+    }
+
+    /// Draws the maze with every tile on any of the best paths marked as 'O'.
+    pub fn render(&self, solved: &SolvedMaze) -> Vec<String> {
+        self.lines
+            .iter()
+            .enumerate()
+            .map(|(y, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(|(x, c)| {
+                        if solved.shortest_tiles.contains(&Coordinate { x, y }) {
+                            'O'
+                        } else {
+                            c
+                        }
+                    })
+                    .collect()
+            })
+            .collect()
+    }
 }
 
 #[derive(Debug)]
-struct SolvedMaze {
-    shortest_distance: u32,
-    shortest_tiles: usize,
+pub struct SolvedMaze {
+    pub shortest_distance: u32,
+    pub shortest_tiles: FxHashSet<Coordinate>,
+    /// Every best path as the list of tiles it crosses, turns on the spot are not repeated.
+    pub paths: Vec<Vec<Coordinate>>,
 }
 
 #[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
-struct Coordinate {
-    x: usize,
-    y: usize,
+pub struct Coordinate {
+    pub x: usize,
+    pub y: usize,
 }
 
 impl Coordinate {
@@ -150,7 +199,7 @@ impl Coordinate {
 }
 
 #[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
-enum Orientation {
+pub enum Orientation {
     North,
     East,
     South,
@@ -158,6 +207,13 @@ enum Orientation {
 }
 
 impl Orientation {
+    const ALL: [Orientation; 4] = [
+        Orientation::North,
+        Orientation::East,
+        Orientation::South,
+        Orientation::West,
+    ];
+
     fn clockwise(&self) -> Self {
         match self {
             Orientation::North => Orientation::East,
@@ -184,30 +240,34 @@ struct Node {
 }
 
 impl Node {
-    fn neighbours(&self) -> Vec<(Node, u32)> {
-        vec![
-            (
+    fn neighbours(&self, rules: &Rules) -> Vec<(Node, u32)> {
+        let turn = |orientation: Orientation, cost: Option<u32>| {
+            cost.map(|cost| {
+                (
+                    Node {
+                        coordinate: self.coordinate,
+                        orientation,
+                    },
+                    cost,
+                )
+            })
+        };
+
+        [
+            Some((
                 Node {
                     coordinate: self.coordinate.next(&self.orientation),
                     orientation: self.orientation,
                 },
-                1,
-            ),
-            (
-                Node {
-                    coordinate: self.coordinate,
-                    orientation: self.orientation.clockwise(),
-                },
-                1_000,
-            ),
-            (
-                Node {
-                    coordinate: self.coordinate,
-                    orientation: self.orientation.counter_clockwise(),
-                },
-                1_000,
-            ),
+                rules.step_cost,
+            )),
+            turn(self.orientation.clockwise(), rules.turn_cost),
+            turn(self.orientation.counter_clockwise(), rules.turn_cost),
+            turn(self.orientation.clockwise().clockwise(), rules.u_turn_cost),
         ]
+        .into_iter()
+        .flatten()
+        .collect()
     }
 }
 
@@ -216,6 +276,7 @@ mod tests {
     use itertools::Itertools;
 
     use super::*;
+    use crate::{answers, inputs};
 
     #[test]
     fn day_16_part_01_sample() {
@@ -262,11 +323,12 @@ mod tests {
 
     #[test]
     fn day_16_part_01_solution() {
-        let input = include_str!("../../inputs/day_16.txt")
-            .lines()
-            .collect_vec();
+        let Some(input) = inputs::load(2024, 16) else {
+            return;
+        };
+        let input = input.lines().collect_vec();
 
-        assert_eq!(94_444, solve_1(&input));
+        assert_eq!(answers::expected(2024, 16, 1), solve_1(&input).to_string());
     }
 
     #[test]
@@ -314,10 +376,56 @@ mod tests {
 
     #[test]
     fn day_16_part_02_solution() {
-        let input = include_str!("../../inputs/day_16.txt")
-            .lines()
-            .collect_vec();
+        let Some(input) = inputs::load(2024, 16) else {
+            return;
+        };
+        let input = input.lines().collect_vec();
+
+        assert_eq!(answers::expected(2024, 16, 2), solve_2(&input).to_string());
+    }
 
-        assert_eq!(502, solve_2(&input));
+    #[test]
+    fn day_16_configurable_rules() {
+        #[rustfmt::skip]
+        let sample = vec![
+            "#####",
+            "#E..#",
+            "###.#",
+            "#S..#",
+            "#####",
+        ];
+
+        let maze = Maze::new(&sample, &Rules::default());
+        let solved = maze.solve().unwrap();
+        assert_eq!(2_006, solved.shortest_distance);
+        #[rustfmt::skip]
+        assert_eq!(
+            vec![
+                "#####",
+                "#OOO#",
+                "###O#",
+                "#OOO#",
+                "#####",
+            ],
+            maze.render(&solved)
+        );
+        assert_eq!(1, solved.paths.len());
+        assert_eq!(7, solved.paths[0].len());
+
+        let cheap_turns = Rules {
+            step_cost: 10,
+            turn_cost: Some(1),
+            ..Rules::default()
+        };
+        let solved = Maze::new(&sample, &cheap_turns).solve().unwrap();
+        assert_eq!(62, solved.shortest_distance);
+
+        let facing_west = Rules {
+            turn_cost: None,
+            u_turn_cost: Some(5),
+            start_orientation: Orientation::West,
+            ..Rules::default()
+        };
+        assert!(Maze::new(&sample, &facing_west).solve().is_none());
     }
 }
@@ -139,6 +139,7 @@ impl Instruction {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{answers, inputs};
     use itertools::Itertools;
 
     #[test]
@@ -156,11 +157,12 @@ mod tests {
 
     #[test]
     fn day_17_part_01_solution() {
-        let input = include_str!("../../inputs/day_17.txt")
-            .lines()
-            .collect_vec();
+        let Some(input) = inputs::load(2024, 17) else {
+            return;
+        };
+        let input = input.lines().collect_vec();
 
-        assert_eq!("1,6,3,6,5,6,5,1,7", solve_1(&input));
+        assert_eq!(answers::expected(2024, 17, 1), solve_1(&input).to_string());
     }
 
     #[test]
@@ -178,10 +180,11 @@ mod tests {
 
     #[test]
     fn day_17_part_02_solution() {
-        let input = include_str!("../../inputs/day_17.txt")
-            .lines()
-            .collect_vec();
+        let Some(input) = inputs::load(2024, 17) else {
+            return;
+        };
+        let input = input.lines().collect_vec();
 
-        assert_eq!(247_839_653_009_594, solve_2(&input));
+        assert_eq!(answers::expected(2024, 17, 2), solve_2(&input).to_string());
     }
 }
@@ -78,6 +78,7 @@ mod tests {
     use itertools::Itertools;
 
     use super::*;
+    use crate::{answers, inputs};
 
     #[test]
     fn day_18_part_01_sample() {
@@ -115,11 +116,15 @@ mod tests {
 
     #[test]
     fn day_18_part_01_solution() {
-        let input = include_str!("../../inputs/day_18.txt")
-            .lines()
-            .collect_vec();
-
-        assert_eq!(306, solve_1(&input, 1024, 70));
+        let Some(input) = inputs::load(2024, 18) else {
+            return;
+        };
+        let input = input.lines().collect_vec();
+
+        assert_eq!(
+            answers::expected(2024, 18, 1),
+            solve_1(&input, 1024, 70).to_string()
+        );
     }
 
     #[test]
@@ -158,10 +163,14 @@ mod tests {
 
     #[test]
     fn day_18_part_02_solution() {
-        let input = include_str!("../../inputs/day_18.txt")
-            .lines()
-            .collect_vec();
-
-        assert_eq!("38,63", solve_2(&input, 70));
+        let Some(input) = inputs::load(2024, 18) else {
+            return;
+        };
+        let input = input.lines().collect_vec();
+
+        assert_eq!(
+            answers::expected(2024, 18, 2),
+            solve_2(&input, 70).to_string()
+        );
     }
 }
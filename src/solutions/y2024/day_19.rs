@@ -0,0 +1,271 @@
+use crate::parallel;
+use crate::util::trie::Trie;
+use itertools::Itertools;
+
+pub fn solve_1(towels: &[&str]) -> usize {
+    let onsen = Onsen::new(towels[0]);
+    let designs = towels.iter().skip(2).copied().collect_vec();
+
+    parallel::fold(
+        &designs,
+        || 0,
+        |possible, design| possible + (onsen.ways_count(design) > 0) as usize,
+        |a, b| a + b,
+    )
+}
+
+pub fn solve_2(towels: &[&str]) -> u64 {
+    let onsen = Onsen::new(towels[0]);
+    let designs = towels.iter().skip(2).copied().collect_vec();
+
+    parallel::fold(
+        &designs,
+        || 0,
+        |ways, design| ways + onsen.ways_count(design),
+        |a, b| a + b,
+    )
+}
+
+/// All available towel patterns, indexed once in a trie and matched against any design.
+#[derive(Debug, Clone)]
+pub struct Onsen {
+    patterns: Vec<String>,
+    trie: Trie,
+}
+
+impl Onsen {
+    pub fn new(patterns: &str) -> Self {
+        let mut trie = Trie::new();
+        let mut unique = vec![];
+
+        for pattern in patterns
+            .split(", ")
+            .map(str::trim)
+            .filter(|p| !p.is_empty())
+        {
+            if trie.insert(pattern) == unique.len() {
+                unique.push(pattern.to_string());
+            }
+        }
+
+        Self {
+            patterns: unique,
+            trie,
+        }
+    }
+
+    pub fn ways_count(&self, design: &str) -> u64 {
+        self.ways_per_suffix(design.as_bytes(), None)[0]
+    }
+
+    /// Number of arrangements for every suffix of the design, indexed by where it starts.
+    /// The pattern `excluded` is never matched against the design as a whole.
+    fn ways_per_suffix(&self, design: &[u8], excluded: Option<usize>) -> Vec<u64> {
+        let mut ways = vec![0; design.len() + 1];
+        ways[design.len()] = 1;
+
+        for start in (0..design.len()).rev() {
+            ways[start] = self
+                .trie
+                .prefixes(&design[start..])
+                .filter(|&(len, pattern)| {
+                    !(start == 0 && len == design.len() && excluded == Some(pattern))
+                })
+                .map(|(len, _)| ways[start + len])
+                .sum();
+        }
+
+        ways
+    }
+
+    /// The arrangement using the fewest towels, if there is any.
+    pub fn shortest_arrangement(&self, design: &str) -> Option<Vec<&str>> {
+        let design = design.as_bytes();
+
+        // Per suffix the fewest towels needed, with the first towel to use
+        let mut fewest: Vec<Option<(usize, usize)>> = vec![None; design.len() + 1];
+        fewest[design.len()] = Some((0, 0));
+
+        for start in (0..design.len()).rev() {
+            fewest[start] = self
+                .trie
+                .prefixes(&design[start..])
+                .filter_map(|(len, pattern)| {
+                    fewest[start + len].map(|(count, _)| (count + 1, pattern))
+                })
+                .min_by_key(|&(count, _)| count);
+        }
+
+        fewest[0]?;
+
+        let mut arrangement = vec![];
+        let mut start = 0;
+        while start < design.len() {
+            let pattern = &self.patterns[fewest[start].unwrap().1];
+            arrangement.push(pattern.as_str());
+            start += pattern.len();
+        }
+
+        Some(arrangement)
+    }
+
+    /// Lazily lists every arrangement of the design.
+    pub fn arrangements<'a>(&'a self, design: &'a str) -> Arrangements<'a> {
+        let ways = self.ways_per_suffix(design.as_bytes(), None);
+        let stack = if ways[0] > 0 {
+            vec![(0, vec![])]
+        } else {
+            vec![]
+        };
+
+        Arrangements {
+            onsen: self,
+            design: design.as_bytes(),
+            ways,
+            stack,
+        }
+    }
+
+    /// Patterns that can themselves be arranged out of other, smaller, patterns.
+    pub fn redundant_patterns(&self) -> Vec<&str> {
+        self.patterns
+            .iter()
+            .enumerate()
+            .filter(|(idx, pattern)| self.ways_per_suffix(pattern.as_bytes(), Some(*idx))[0] > 0)
+            .map(|(_, pattern)| pattern.as_str())
+            .collect()
+    }
+}
+
+#[derive(Debug)]
+pub struct Arrangements<'a> {
+    onsen: &'a Onsen,
+    design: &'a [u8],
+    ways: Vec<u64>,
+    stack: Vec<(usize, Vec<usize>)>,
+}
+
+impl<'a> Iterator for Arrangements<'a> {
+    type Item = Vec<&'a str>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((start, arrangement)) = self.stack.pop() {
+            if start == self.design.len() {
+                return Some(
+                    arrangement
+                        .iter()
+                        .map(|&pattern| self.onsen.patterns[pattern].as_str())
+                        .collect(),
+                );
+            }
+
+            // Only follow towels leaving a suffix that can still be arranged
+            let next = self
+                .onsen
+                .trie
+                .prefixes(&self.design[start..])
+                .filter(|&(len, _)| self.ways[start + len] > 0)
+                .collect_vec();
+
+            next.into_iter().rev().for_each(|(len, pattern)| {
+                let mut arrangement = arrangement.clone();
+                arrangement.push(pattern);
+                self.stack.push((start + len, arrangement));
+            });
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::*;
+    use crate::{answers, inputs};
+
+    #[test]
+    fn day_19_part_01_sample() {
+        let sample = vec![
+            "r, wr, b, g, bwu, rb, gb, br",
+            "",
+            "brwrr",
+            "bggr",
+            "gbbr",
+            "rrbgbr",
+            "ubwu",
+            "bwurrg",
+            "brgr",
+            "bbrgwb",
+        ];
+
+        assert_eq!(6, solve_1(&sample));
+    }
+
+    #[test]
+    fn day_19_part_01_solution() {
+        let Some(input) = inputs::load(2024, 19) else {
+            return;
+        };
+        let input = input.lines().collect_vec();
+
+        assert_eq!(answers::expected(2024, 19, 1), solve_1(&input).to_string());
+    }
+
+    #[test]
+    fn day_19_part_02_sample() {
+        let sample = vec![
+            "r, wr, b, g, bwu, rb, gb, br",
+            "",
+            "brwrr",
+            "bggr",
+            "gbbr",
+            "rrbgbr",
+            "ubwu",
+            "bwurrg",
+            "brgr",
+            "bbrgwb",
+        ];
+
+        assert_eq!(16, solve_2(&sample));
+    }
+
+    #[test]
+    fn day_19_part_02_solution() {
+        let Some(input) = inputs::load(2024, 19) else {
+            return;
+        };
+        let input = input.lines().collect_vec();
+
+        assert_eq!(answers::expected(2024, 19, 2), solve_2(&input).to_string());
+    }
+
+    #[test]
+    fn day_19_arrangements() {
+        let onsen = Onsen::new("r, wr, b, g, bwu, rb, gb, br");
+
+        assert_eq!(
+            vec![vec!["b", "r", "wr", "r"], vec!["br", "wr", "r"]],
+            onsen.arrangements("brwrr").collect_vec()
+        );
+        assert_eq!(
+            onsen.ways_count("rrbgbr") as usize,
+            onsen.arrangements("rrbgbr").count()
+        );
+        assert_eq!(0, onsen.arrangements("ubwu").count());
+
+        assert_eq!(
+            Some(vec!["br", "wr", "r"]),
+            onsen.shortest_arrangement("brwrr")
+        );
+        assert_eq!(None, onsen.shortest_arrangement("bbrgwb"));
+    }
+
+    #[test]
+    fn day_19_redundant_patterns() {
+        let onsen = Onsen::new("r, wr, b, g, bwu, rb, gb, br");
+
+        assert_eq!(vec!["rb", "gb", "br"], onsen.redundant_patterns());
+    }
+}
@@ -0,0 +1,383 @@
+use crate::parallel;
+use itertools::Itertools;
+use std::collections::{BTreeMap, VecDeque};
+
+pub fn solve_1(track: &[&str], min_save: i32) -> usize {
+    solve(track, min_save, 2)
+}
+
+pub fn solve_2(track: &[&str], min_save: i32) -> usize {
+    solve(track, min_save, 20)
+}
+
+fn solve(track: &[&str], min_save: i32, max_cheat_dist: i32) -> usize {
+    Track::new(track).count_cheats(max_cheat_dist, min_save)
+}
+
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct Cheat {
+    pub start: Coordinate,
+    pub end: Coordinate,
+    pub length: i32,
+    pub saved: i32,
+}
+
+/// Racetrack stored as a flat grid, every tile holding its distance from start and to end.
+///
+/// Both distances are needed as the track can branch, the time with a cheat is the time
+/// to reach its start, plus its length, plus the remaining time from its end.
+#[derive(Debug)]
+pub struct Track {
+    from_start: Vec<Option<i32>>,
+    to_end: Vec<Option<i32>>,
+    best_time: i32,
+    width: i32,
+    height: i32,
+}
+
+impl Track {
+    pub fn new(track: &[&str]) -> Self {
+        let height = track.len() as i32;
+        let width = track[0].len() as i32;
+
+        let tiles = track.iter().flat_map(|line| line.chars()).collect_vec();
+        let find = |tile: char| {
+            let idx = tiles.iter().position(|&c| c == tile).unwrap() as i32;
+            Coordinate {
+                x: idx % width,
+                y: idx / width,
+            }
+        };
+        let road = tiles.iter().map(|&c| c != '#').collect_vec();
+        let start = find('S');
+        let end = find('E');
+
+        let from_start = Self::run(&road, width, height, start);
+        let to_end = Self::run(&road, width, height, end);
+        let best_time = from_start[(end.y * width + end.x) as usize].unwrap();
+
+        Self {
+            from_start,
+            to_end,
+            best_time,
+            width,
+            height,
+        }
+    }
+
+    pub fn best_time(&self) -> i32 {
+        self.best_time
+    }
+
+    fn run(road: &[bool], width: i32, height: i32, from: Coordinate) -> Vec<Option<i32>> {
+        let mut to_visit = VecDeque::<(Coordinate, i32)>::new();
+        let mut visited = vec![None; road.len()];
+
+        to_visit.push_back((from, 0));
+
+        while let Some((node, distance)) = to_visit.pop_front() {
+            let idx = (node.y * width + node.x) as usize;
+            if visited[idx].is_some() {
+                continue;
+            }
+
+            visited[idx] = Some(distance);
+
+            [(1, 0), (-1, 0), (0, 1), (0, -1)]
+                .map(|(dx, dy)| Coordinate {
+                    x: node.x + dx,
+                    y: node.y + dy,
+                })
+                .into_iter()
+                .filter(|neighbour| {
+                    (0..width).contains(&neighbour.x)
+                        && (0..height).contains(&neighbour.y)
+                        && road[(neighbour.y * width + neighbour.x) as usize]
+                })
+                .for_each(|neighbour| {
+                    to_visit.push_back((neighbour, distance + 1));
+                });
+        }
+
+        visited
+    }
+
+    /// Every tile on the track, with the time it takes to reach it.
+    fn track(&self) -> Vec<(i32, i32)> {
+        (0..self.from_start.len())
+            .filter_map(|idx| self.from_start[idx].map(|time| (idx as i32, time)))
+            .collect()
+    }
+
+    /// All cheats of at most `max_cheat_dist` picoseconds starting from the tile at `idx`,
+    /// reached at `time`, saving any time at all.
+    fn cheats_from(
+        &self,
+        (idx, time): (i32, i32),
+        max_cheat_dist: i32,
+    ) -> impl Iterator<Item = Cheat> + '_ {
+        let start = Coordinate {
+            x: idx % self.width,
+            y: idx / self.width,
+        };
+
+        (-max_cheat_dist..=max_cheat_dist)
+            .flat_map(move |dx| {
+                (-max_cheat_dist + dx.abs()..=max_cheat_dist - dx.abs()).map(move |dy| (dx, dy))
+            })
+            .filter_map(move |(dx, dy)| {
+                let end = Coordinate {
+                    x: start.x + dx,
+                    y: start.y + dy,
+                };
+                if !(0..self.width).contains(&end.x) || !(0..self.height).contains(&end.y) {
+                    return None;
+                }
+
+                let remaining = self.to_end[(end.y * self.width + end.x) as usize]?;
+                let length = dx.abs() + dy.abs();
+                let saved = self.best_time - (time + length + remaining);
+
+                (saved > 0).then_some(Cheat {
+                    start,
+                    end,
+                    length,
+                    saved,
+                })
+            })
+    }
+
+    pub fn count_cheats(&self, max_cheat_dist: i32, min_save: i32) -> usize {
+        parallel::fold(
+            &self.track(),
+            || 0,
+            |count, &tile| {
+                count
+                    + self
+                        .cheats_from(tile, max_cheat_dist)
+                        .filter(|cheat| cheat.saved >= min_save)
+                        .count()
+            },
+            |a, b| a + b,
+        )
+    }
+
+    /// Number of cheats per cheat length, and per time saved, for cheats saving any time.
+    pub fn histogram(&self, max_cheat_dist: i32) -> BTreeMap<i32, BTreeMap<i32, usize>> {
+        parallel::fold(
+            &self.track(),
+            BTreeMap::new,
+            |mut histogram, &tile| {
+                self.cheats_from(tile, max_cheat_dist)
+                    .for_each(|cheat| Self::count(&mut histogram, cheat.length, cheat.saved, 1));
+                histogram
+            },
+            |mut histogram, other| {
+                other.into_iter().for_each(|(length, saves)| {
+                    saves.into_iter().for_each(|(saved, count)| {
+                        Self::count(&mut histogram, length, saved, count)
+                    })
+                });
+                histogram
+            },
+        )
+    }
+
+    fn count(
+        histogram: &mut BTreeMap<i32, BTreeMap<i32, usize>>,
+        length: i32,
+        saved: i32,
+        count: usize,
+    ) {
+        *histogram
+            .entry(length)
+            .or_default()
+            .entry(saved)
+            .or_default() += count;
+    }
+
+    /// The `n` cheats saving the most time, ties broken by position.
+    pub fn best_cheats(&self, max_cheat_dist: i32, n: usize) -> Vec<Cheat> {
+        parallel::fold(
+            &self.track(),
+            Vec::new,
+            |mut cheats, &tile| {
+                cheats.extend(self.cheats_from(tile, max_cheat_dist));
+                cheats
+            },
+            |mut cheats, other| {
+                cheats.extend(other);
+                cheats
+            },
+        )
+        .into_iter()
+        .sorted_by_key(|cheat| (-cheat.saved, cheat.start, cheat.end))
+        .take(n)
+        .collect()
+    }
+}
+
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct Coordinate {
+    pub x: i32,
+    pub y: i32,
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::*;
+    use crate::{answers, inputs};
+
+    #[test]
+    fn day_20_part_01_sample() {
+        let sample = vec![
+            "###############",
+            "#...#...#.....#",
+            "#.#.#.#.#.###.#",
+            "#S#...#.#.#...#",
+            "#######.#.#.###",
+            "#######.#.#...#",
+            "#######.#.###.#",
+            "###..E#...#...#",
+            "###.#######.###",
+            "#...###...#...#",
+            "#.#####.#.###.#",
+            "#.#...#.#.#...#",
+            "#.#.#.#.#.#.###",
+            "#...#...#...###",
+            "###############",
+        ];
+
+        assert_eq!(
+            14 + 14 + 2 + 4 + 2 + 3 + 1 + 1 + 1 + 1 + 1,
+            solve_1(&sample, 1)
+        );
+    }
+
+    #[test]
+    fn day_20_part_01_solution() {
+        let Some(input) = inputs::load(2024, 20) else {
+            return;
+        };
+        let input = input.lines().collect_vec();
+
+        assert_eq!(
+            answers::expected(2024, 20, 1),
+            solve_1(&input, 100).to_string()
+        );
+    }
+
+    #[test]
+    fn day_20_part_02_sample() {
+        let sample = vec![
+            "###############",
+            "#...#...#.....#",
+            "#.#.#.#.#.###.#",
+            "#S#...#.#.#...#",
+            "#######.#.#.###",
+            "#######.#.#...#",
+            "#######.#.###.#",
+            "###..E#...#...#",
+            "###.#######.###",
+            "#...###...#...#",
+            "#.#####.#.###.#",
+            "#.#...#.#.#...#",
+            "#.#.#.#.#.#.###",
+            "#...#...#...###",
+            "###############",
+        ];
+
+        assert_eq!(
+            32 + 31 + 29 + 39 + 25 + 23 + 20 + 19 + 12 + 14 + 12 + 22 + 4 + 3,
+            solve_2(&sample, 50)
+        );
+    }
+
+    #[test]
+    fn day_20_part_02_solution() {
+        let Some(input) = inputs::load(2024, 20) else {
+            return;
+        };
+        let input = input.lines().collect_vec();
+
+        assert_eq!(
+            answers::expected(2024, 20, 2),
+            solve_2(&input, 100).to_string()
+        );
+    }
+
+    #[test]
+    fn day_20_cheat_analysis() {
+        let sample = vec![
+            "###############",
+            "#...#...#.....#",
+            "#.#.#.#.#.###.#",
+            "#S#...#.#.#...#",
+            "#######.#.#.###",
+            "#######.#.#...#",
+            "#######.#.###.#",
+            "###..E#...#...#",
+            "###.#######.###",
+            "#...###...#...#",
+            "#.#####.#.###.#",
+            "#.#...#.#.#...#",
+            "#.#.#.#.#.#.###",
+            "#...#...#...###",
+            "###############",
+        ];
+        let track = Track::new(&sample);
+
+        assert_eq!(84, track.best_time());
+        assert_eq!(
+            BTreeMap::from([(
+                2,
+                BTreeMap::from([
+                    (2, 14),
+                    (4, 14),
+                    (6, 2),
+                    (8, 4),
+                    (10, 2),
+                    (12, 3),
+                    (20, 1),
+                    (36, 1),
+                    (38, 1),
+                    (40, 1),
+                    (64, 1),
+                ])
+            )]),
+            track.histogram(2)
+        );
+        assert_eq!(
+            vec![Cheat {
+                start: Coordinate { x: 7, y: 7 },
+                end: Coordinate { x: 5, y: 7 },
+                length: 2,
+                saved: 64
+            }],
+            track.best_cheats(2, 1)
+        );
+    }
+
+    #[test]
+    fn day_20_branching_track() {
+        // A dead end branches off the finish, ending close to other parts of itself
+        // Measuring only the distance to the end would make cheats along it look worthwhile
+        #[rustfmt::skip]
+        let sample = vec![
+            "#######",
+            "#S...E#",
+            "#####.#",
+            "#.....#",
+            "#.#####",
+            "#.....#",
+            "#######",
+        ];
+        let track = Track::new(&sample);
+
+        assert_eq!(4, track.best_time());
+        assert_eq!(0, track.count_cheats(2, 1));
+        assert!(track.histogram(20).is_empty());
+    }
+}
@@ -308,6 +308,7 @@ mod tests {
     use itertools::Itertools;
 
     use super::*;
+    use crate::{answers, inputs};
 
     #[test]
     fn day_21_part_01_sample() {
@@ -325,11 +326,12 @@ mod tests {
 
     #[test]
     fn day_21_part_01_solution() {
-        let input = include_str!("../../inputs/day_21.txt")
-            .lines()
-            .collect_vec();
+        let Some(input) = inputs::load(2024, 21) else {
+            return;
+        };
+        let input = input.lines().collect_vec();
 
-        assert_eq!(211_930, solve_1(&input));
+        assert_eq!(answers::expected(2024, 21, 1), solve_1(&input).to_string());
     }
 
     #[test]
@@ -339,10 +341,11 @@ mod tests {
 
     #[test]
     fn day_21_part_02_solution() {
-        let input = include_str!("../../inputs/day_21.txt")
-            .lines()
-            .collect_vec();
+        let Some(input) = inputs::load(2024, 21) else {
+            return;
+        };
+        let input = input.lines().collect_vec();
 
-        assert_eq!(263_492_840_501_566, solve_2(&input));
+        assert_eq!(answers::expected(2024, 21, 2), solve_2(&input).to_string());
     }
 }
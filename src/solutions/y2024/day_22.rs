@@ -0,0 +1,376 @@
+use crate::{parallel, util};
+use itertools::Itertools;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io::BufRead;
+use std::{io, iter};
+
+pub fn solve_1(secrets: &[&str]) -> i64 {
+    Market::new(secrets, Prng::default())
+        .buyers
+        .iter()
+        .map(|secret| secret.predict(2_000))
+        .sum()
+}
+
+pub fn solve_2(secrets: &[&str]) -> i64 {
+    Market::new(secrets, Prng::default())
+        .analyse(2_000)
+        .best_sequence()
+        .1
+}
+
+/// Same as [`solve_1`], reading the secrets line by line.
+pub fn solve_1_from(secrets: impl BufRead) -> io::Result<i64> {
+    let prng = Prng::default();
+    let mut total = 0;
+    util::for_each_line(secrets, |secret| {
+        total += Secret::new(secret, prng.clone()).predict(2_000)
+    })?;
+
+    Ok(total)
+}
+
+/// Same as [`solve_2`], reading the secrets line by line into a single table of bananas,
+/// rather than one per thread.
+pub fn solve_2_from(secrets: impl BufRead) -> io::Result<i64> {
+    let prng = Prng::default();
+    let mut bananas = vec![0; NR_SEQUENCES];
+    util::for_each_line(secrets, |secret| {
+        Secret::new(secret, prng.clone()).add_sequences(2_000, &mut bananas)
+    })?;
+
+    Ok(Analysis { bananas }.best_sequence().1)
+}
+
+/// Number of distinct sequences of four price changes, each change being in -9..=9.
+const NR_SEQUENCES: usize = 19 * 19 * 19 * 19;
+
+pub type Sequence = [i8; 4];
+
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum Step {
+    /// Mixes in the secret multiplied by the given factor.
+    Multiply(i64),
+    /// Mixes in the secret divided by the given divisor.
+    Divide(i64),
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum PrngError {
+    DivideByZero,
+    /// Pruning needs a positive modulus.
+    InvalidModulus(i64),
+}
+
+impl Display for PrngError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PrngError::DivideByZero => write!(f, "step divides by zero"),
+            PrngError::InvalidModulus(modulus) => write!(f, "modulus {modulus} is not positive"),
+        }
+    }
+}
+
+impl Error for PrngError {}
+
+/// Pipeline of steps evolving a secret, every step mixing (XOR) a value into the secret
+/// before pruning it (modulo).
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Prng {
+    steps: Vec<Step>,
+    modulus: i64,
+}
+
+impl Prng {
+    pub fn new(steps: Vec<Step>, modulus: i64) -> Result<Self, PrngError> {
+        if steps.contains(&Step::Divide(0)) {
+            return Err(PrngError::DivideByZero);
+        }
+        if modulus <= 0 {
+            return Err(PrngError::InvalidModulus(modulus));
+        }
+
+        Ok(Self { steps, modulus })
+    }
+
+    /// Mixing happens in `i128`, as a pruned secret times any `i64` factor fits in there.
+    pub fn next(&self, secret: i64) -> i64 {
+        self.steps.iter().fold(secret, |secret, step| {
+            let secret = secret as i128;
+            let value = match *step {
+                Step::Multiply(factor) => secret * factor as i128,
+                Step::Divide(divisor) => secret / divisor as i128,
+            };
+
+            (value ^ secret).rem_euclid(self.modulus as i128) as i64
+        })
+    }
+}
+
+impl Default for Prng {
+    fn default() -> Self {
+        Self {
+            steps: vec![Step::Multiply(64), Step::Divide(32), Step::Multiply(2048)],
+            modulus: 16_777_216,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Market {
+    buyers: Vec<Secret>,
+}
+
+impl Market {
+    pub fn new(secrets: &[&str], prng: Prng) -> Self {
+        let buyers = secrets
+            .iter()
+            .map(|secret| Secret::new(secret, prng.clone()))
+            .collect();
+
+        Self { buyers }
+    }
+
+    /// Sums the bananas every sequence would buy across all buyers,
+    /// each thread filling a table of its own before they are added up.
+    pub fn analyse(&self, time: usize) -> Analysis {
+        let bananas = parallel::fold(
+            &self.buyers,
+            || vec![0; NR_SEQUENCES],
+            |mut bananas, buyer| {
+                buyer.add_sequences(time, &mut bananas);
+                bananas
+            },
+            |mut bananas, other| {
+                bananas.iter_mut().zip(other).for_each(|(b, o)| *b += o);
+                bananas
+            },
+        );
+
+        Analysis { bananas }
+    }
+
+    /// Bananas bought by each buyer, in order, when selling on the given sequence.
+    pub fn bananas_per_buyer(&self, sequence: Sequence, time: usize) -> Vec<i64> {
+        let target = encode(sequence);
+
+        self.buyers
+            .iter()
+            .map(|buyer| {
+                buyer
+                    .sequences(time)
+                    .find(|&(sequence, _)| sequence == target)
+                    .map(|(_, price)| price)
+                    .unwrap_or(0)
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Analysis {
+    bananas: Vec<i64>,
+}
+
+impl Analysis {
+    pub fn bananas(&self, sequence: Sequence) -> i64 {
+        self.bananas[encode(sequence)]
+    }
+
+    pub fn best_sequence(&self) -> (Sequence, i64) {
+        self.top(1)[0]
+    }
+
+    /// The `n` sequences buying the most bananas, ties broken by sequence.
+    pub fn top(&self, n: usize) -> Vec<(Sequence, i64)> {
+        self.bananas
+            .iter()
+            .enumerate()
+            .map(|(idx, &bananas)| (decode(idx), bananas))
+            .sorted_by_key(|&(sequence, bananas)| (-bananas, sequence))
+            .take(n)
+            .collect()
+    }
+}
+
+/// Turns a sequence of changes into a base 19 index, each digit shifted up by 9.
+fn encode(sequence: Sequence) -> usize {
+    sequence
+        .iter()
+        .fold(0, |idx, &change| idx * 19 + (change + 9) as usize)
+}
+
+fn decode(mut idx: usize) -> Sequence {
+    let mut sequence = [0; 4];
+
+    for change in sequence.iter_mut().rev() {
+        *change = (idx % 19) as i8 - 9;
+        idx /= 19;
+    }
+
+    sequence
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+struct Secret {
+    initial: i64,
+    prng: Prng,
+}
+
+impl Secret {
+    fn new(secret: &str, prng: Prng) -> Self {
+        Self {
+            initial: secret.parse().unwrap(),
+            prng,
+        }
+    }
+
+    fn predict(&self, time: usize) -> i64 {
+        self.numbers(time)[time]
+    }
+
+    /// Every encoded sequence of four changes with the price right after it, in order.
+    ///
+    /// Prices are always the last digit of the secret, whatever the modulus of the [`Prng`],
+    /// which keeps every change within -9..=9 as the table of [`NR_SEQUENCES`] relies on.
+    fn sequences(&self, time: usize) -> impl Iterator<Item = (usize, i64)> {
+        let prices = self
+            .numbers(time)
+            .iter()
+            .map(|secret| secret % 10)
+            .collect_vec();
+
+        (4..prices.len()).map(move |idx| {
+            let sequence = [
+                prices[idx - 3] - prices[idx - 4],
+                prices[idx - 2] - prices[idx - 3],
+                prices[idx - 1] - prices[idx - 2],
+                prices[idx] - prices[idx - 1],
+            ]
+            .map(|change| change as i8);
+
+            (encode(sequence), prices[idx])
+        })
+    }
+
+    /// Adds the price at the first occurrence of every sequence, the monkey sells right away.
+    fn add_sequences(&self, time: usize, bananas: &mut [i64]) {
+        let mut seen = vec![false; NR_SEQUENCES];
+
+        for (sequence, price) in self.sequences(time) {
+            if !seen[sequence] {
+                seen[sequence] = true;
+                bananas[sequence] += price;
+            }
+        }
+    }
+
+    fn numbers(&self, time: usize) -> Vec<i64> {
+        iter::successors(Some(self.initial), |&secret| Some(self.prng.next(secret)))
+            .take(time + 1)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::*;
+    use crate::{answers, inputs};
+
+    #[test]
+    fn day_22_part_01_sample() {
+        #[rustfmt::skip]
+        let sample = vec![
+            "1",
+            "10",
+            "100",
+            "2024",
+        ];
+
+        assert_eq!(37_327_623, solve_1(&sample));
+    }
+
+    #[test]
+    fn day_22_part_01_solution() {
+        let Some(input) = inputs::load(2024, 22) else {
+            return;
+        };
+        let input = input.lines().collect_vec();
+
+        assert_eq!(answers::expected(2024, 22, 1), solve_1(&input).to_string());
+    }
+
+    #[test]
+    fn day_22_part_02_sample() {
+        #[rustfmt::skip]
+        let sample = vec![
+            "1",
+            "2",
+            "3",
+            "2024",
+        ];
+
+        assert_eq!(23, solve_2(&sample));
+    }
+
+    #[test]
+    fn day_22_part_02_solution() {
+        let Some(input) = inputs::load(2024, 22) else {
+            return;
+        };
+        let input = input.lines().collect_vec();
+
+        assert_eq!(answers::expected(2024, 22, 2), solve_2(&input).to_string());
+    }
+
+    #[test]
+    fn day_22_streamed() {
+        assert_eq!(
+            37_327_623,
+            solve_1_from("1\n10\n100\n2024\n".as_bytes()).unwrap()
+        );
+        assert_eq!(23, solve_2_from("1\n2\n3\n2024\n".as_bytes()).unwrap());
+    }
+
+    #[test]
+    fn day_22_sequence_analytics() {
+        #[rustfmt::skip]
+        let sample = vec![
+            "1",
+            "2",
+            "3",
+            "2024",
+        ];
+        let market = Market::new(&sample, Prng::default());
+        let analysis = market.analyse(2_000);
+
+        assert_eq!(([-2, 1, -1, 3], 23), analysis.best_sequence());
+        assert_eq!(
+            vec![7, 7, 0, 9],
+            market.bananas_per_buyer([-2, 1, -1, 3], 2_000)
+        );
+        assert_eq!(3, analysis.top(3).len());
+        assert!(analysis.top(3).iter().all(|&(_, bananas)| bananas <= 23));
+    }
+
+    #[test]
+    fn day_22_configurable_prng() {
+        let prng = Prng::default();
+        assert_eq!(15_887_950, prng.next(123));
+
+        let prng = Prng::new(vec![Step::Multiply(2)], 100).unwrap();
+        assert_eq!(15, prng.next(5));
+
+        let prng = Prng::new(vec![Step::Multiply(i64::MAX)], i64::MAX).unwrap();
+        assert_eq!(i64::MAX - 2, prng.next(2));
+
+        assert_eq!(
+            Err(PrngError::DivideByZero),
+            Prng::new(vec![Step::Divide(0)], 100)
+        );
+        assert_eq!(Err(PrngError::InvalidModulus(0)), Prng::new(vec![], 0));
+    }
+}
@@ -0,0 +1,393 @@
+use crate::util::clique::Clique;
+use crate::util::union_find::UnionFind;
+use itertools::Itertools;
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+
+pub fn solve_1(connections: &[&str]) -> usize {
+    Network::new(connections)
+        .count_triangles(|triangle| triangle.iter().any(|computer| computer.starts_with('t')))
+}
+
+pub fn solve_2(connections: &[&str]) -> String {
+    Network::new(connections).maximum_cliques().cliques[0]
+        .iter()
+        .join(",")
+}
+
+/// Computer name of two lowercase letters, packed as a base 26 number.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct Computer(u16);
+
+impl Computer {
+    pub fn new(name: &str) -> Self {
+        let (first, second) = match *name.as_bytes() {
+            [first, second] if first.is_ascii_lowercase() && second.is_ascii_lowercase() => {
+                (first, second)
+            }
+            _ => panic!("computer names are two letters long, got {name:?}"),
+        };
+
+        Self((first - b'a') as u16 * 26 + (second - b'a') as u16)
+    }
+
+    /// No computer starts with anything but a lowercase letter.
+    pub fn starts_with(&self, letter: char) -> bool {
+        letter.is_ascii_lowercase() && (self.0 / 26) as u8 == letter as u8 - b'a'
+    }
+
+    fn id(&self) -> usize {
+        self.0 as usize
+    }
+}
+
+impl Display for Computer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let first = (b'a' + (self.0 / 26) as u8) as char;
+        let second = (b'a' + (self.0 % 26) as u8) as char;
+
+        write!(f, "{first}{second}")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DegreeStats {
+    pub min: usize,
+    pub max: usize,
+    pub mean: f64,
+    /// Number of computers per degree.
+    pub histogram: BTreeMap<usize, usize>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct MaximumCliques {
+    pub size: usize,
+    /// Every clique of maximum size, each sorted, in order. More than one means a tie.
+    pub cliques: Vec<Vec<Computer>>,
+}
+
+#[derive(Debug)]
+pub struct Network {
+    connections: FxHashMap<usize, FxHashSet<usize>>,
+}
+
+impl Network {
+    pub fn new(connections: &[&str]) -> Self {
+        let connections = connections
+            .iter()
+            .flat_map(|connection| {
+                let (from, to) = connection
+                    .split_once('-')
+                    .map(|(from, to)| (Computer::new(from).id(), Computer::new(to).id()))
+                    .unwrap();
+                [(from, to), (to, from)]
+            })
+            .sorted_by_key(|&(from, _)| from)
+            .chunk_by(|&(from, _)| from)
+            .into_iter()
+            .map(|(from, group)| (from, group.into_iter().map(|(_, to)| to).collect()))
+            .collect();
+
+        Self { connections }
+    }
+
+    pub fn computers(&self) -> Vec<Computer> {
+        self.connections
+            .keys()
+            .map(|&id| Computer(id as u16))
+            .sorted()
+            .collect()
+    }
+
+    pub fn neighbours(&self, computer: Computer) -> Vec<Computer> {
+        self.connections
+            .get(&computer.id())
+            .into_iter()
+            .flatten()
+            .map(|&id| Computer(id as u16))
+            .sorted()
+            .collect()
+    }
+
+    /// Sets of three interconnected computers, each sorted.
+    pub fn triangles(&self) -> Vec<[Computer; 3]> {
+        // Only look for neighbours with a higher ID, to find each triangle once
+        self.connections
+            .iter()
+            .flat_map(|(&a, a_neighbours)| {
+                a_neighbours
+                    .iter()
+                    .filter(move |&&b| b > a)
+                    .flat_map(move |&b| {
+                        self.connections[&b]
+                            .iter()
+                            .filter(move |&&c| c > b && a_neighbours.contains(&c))
+                            .map(move |&c| [a, b, c].map(|id| Computer(id as u16)))
+                    })
+            })
+            .sorted()
+            .collect()
+    }
+
+    pub fn count_triangles(&self, predicate: impl Fn(&[Computer; 3]) -> bool) -> usize {
+        self.triangles()
+            .iter()
+            .filter(|triangle| predicate(triangle))
+            .count()
+    }
+
+    pub fn degree_stats(&self) -> DegreeStats {
+        let degrees = self.connections.values().map(|n| n.len()).collect_vec();
+
+        DegreeStats {
+            min: degrees.iter().min().copied().unwrap_or(0),
+            max: degrees.iter().max().copied().unwrap_or(0),
+            mean: degrees.iter().sum::<usize>() as f64 / degrees.len().max(1) as f64,
+            histogram: degrees.iter().copied().counts().into_iter().collect(),
+        }
+    }
+
+    /// Groups of computers that can reach each other, each sorted, in order.
+    pub fn components(&self) -> Vec<Vec<Computer>> {
+        let computers = self.computers();
+        let index: FxHashMap<Computer, usize> = computers
+            .iter()
+            .enumerate()
+            .map(|(idx, &computer)| (computer, idx))
+            .collect();
+
+        let mut union_find = UnionFind::new(computers.len());
+        self.connections.iter().for_each(|(&from, to)| {
+            to.iter().for_each(|&to| {
+                union_find.union(index[&Computer(from as u16)], index[&Computer(to as u16)])
+            })
+        });
+
+        union_find
+            .sets()
+            .into_iter()
+            .map(|set| set.into_iter().map(|idx| computers[idx]).collect_vec())
+            .sorted()
+            .collect()
+    }
+
+    /// Fraction of the pairs of neighbours of a computer that are connected themselves.
+    pub fn clustering_coefficient(&self, computer: Computer) -> f64 {
+        let neighbours = self.neighbours(computer);
+        let nr_pairs = neighbours.len() * neighbours.len().saturating_sub(1) / 2;
+
+        if nr_pairs == 0 {
+            return 0.0;
+        }
+
+        let nr_linked = neighbours
+            .iter()
+            .tuple_combinations()
+            .filter(|(a, b)| self.connections[&a.id()].contains(&b.id()))
+            .count();
+
+        nr_linked as f64 / nr_pairs as f64
+    }
+
+    pub fn average_clustering_coefficient(&self) -> f64 {
+        let computers = self.computers();
+
+        computers
+            .iter()
+            .map(|&computer| self.clustering_coefficient(computer))
+            .sum::<f64>()
+            / computers.len().max(1) as f64
+    }
+
+    pub fn maximum_cliques(&self) -> MaximumCliques {
+        let cliques = Clique::new(&self.connections).cliques(None);
+        let size = cliques.iter().map(|set| set.len()).max().unwrap_or(0);
+
+        let cliques = cliques
+            .iter()
+            .filter(|set| set.len() == size)
+            .map(|set| {
+                set.iter()
+                    .map(|&id| Computer(id as u16))
+                    .sorted()
+                    .collect_vec()
+            })
+            .sorted()
+            .collect();
+
+        MaximumCliques { size, cliques }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::*;
+    use crate::{answers, inputs};
+
+    #[test]
+    fn day_23_part_01_sample() {
+        #[rustfmt::skip]
+        let sample = vec![
+            "kh-tc",
+            "qp-kh",
+            "de-cg",
+            "ka-co",
+            "yn-aq",
+            "qp-ub",
+            "cg-tb",
+            "vc-aq",
+            "tb-ka",
+            "wh-tc",
+            "yn-cg",
+            "kh-ub",
+            "ta-co",
+            "de-co",
+            "tc-td",
+            "tb-wq",
+            "wh-td",
+            "ta-ka",
+            "td-qp",
+            "aq-cg",
+            "wq-ub",
+            "ub-vc",
+            "de-ta",
+            "wq-aq",
+            "wq-vc",
+            "wh-yn",
+            "ka-de",
+            "kh-ta",
+            "co-tc",
+            "wh-qp",
+            "tb-vc",
+            "td-yn",
+        ];
+
+        assert_eq!(7, solve_1(&sample));
+    }
+
+    #[test]
+    fn day_23_part_01_solution() {
+        let Some(input) = inputs::load(2024, 23) else {
+            return;
+        };
+        let input = input.lines().collect_vec();
+
+        assert_eq!(answers::expected(2024, 23, 1), solve_1(&input).to_string());
+    }
+
+    #[test]
+    fn day_23_part_02_sample() {
+        #[rustfmt::skip]
+        let sample = vec![
+            "kh-tc",
+            "qp-kh",
+            "de-cg",
+            "ka-co",
+            "yn-aq",
+            "qp-ub",
+            "cg-tb",
+            "vc-aq",
+            "tb-ka",
+            "wh-tc",
+            "yn-cg",
+            "kh-ub",
+            "ta-co",
+            "de-co",
+            "tc-td",
+            "tb-wq",
+            "wh-td",
+            "ta-ka",
+            "td-qp",
+            "aq-cg",
+            "wq-ub",
+            "ub-vc",
+            "de-ta",
+            "wq-aq",
+            "wq-vc",
+            "wh-yn",
+            "ka-de",
+            "kh-ta",
+            "co-tc",
+            "wh-qp",
+            "tb-vc",
+            "td-yn",
+        ];
+
+        assert_eq!("co,de,ka,ta", solve_2(&sample));
+    }
+
+    #[test]
+    fn day_23_part_02_solution() {
+        let Some(input) = inputs::load(2024, 23) else {
+            return;
+        };
+        let input = input.lines().collect_vec();
+
+        assert_eq!(answers::expected(2024, 23, 2), solve_2(&input).to_string());
+    }
+
+    #[test]
+    fn day_23_network_analytics() {
+        #[rustfmt::skip]
+        let sample = vec![
+            "ab-ac",
+            "ac-ad",
+            "ad-ab",
+            "ad-ae",
+            "xa-xb",
+            "xb-xc",
+            "xc-xa",
+        ];
+        let network = Network::new(&sample);
+
+        assert_eq!("ad", Computer::new("ad").to_string());
+        assert_eq!(2, network.triangles().len());
+        assert_eq!(
+            1,
+            network.count_triangles(|triangle| triangle.contains(&Computer::new("ab")))
+        );
+
+        let stats = network.degree_stats();
+        assert_eq!((1, 3), (stats.min, stats.max));
+        assert_eq!(BTreeMap::from([(1, 1), (2, 5), (3, 1)]), stats.histogram);
+
+        assert_eq!(
+            vec![4, 3],
+            network.components().iter().map(|c| c.len()).collect_vec()
+        );
+        assert_eq!(
+            1.0 / 3.0,
+            network.clustering_coefficient(Computer::new("ad"))
+        );
+
+        let maximum = network.maximum_cliques();
+        assert_eq!(3, maximum.size);
+        assert_eq!(
+            vec!["ab,ac,ad", "xa,xb,xc"],
+            maximum
+                .cliques
+                .iter()
+                .map(|c| c.iter().join(","))
+                .collect_vec()
+        );
+    }
+
+    #[test]
+    fn day_23_computer_names() {
+        let computer = Computer::new("tc");
+
+        assert_eq!("tc", computer.to_string());
+        assert!(computer.starts_with('t'));
+        assert!(!computer.starts_with('c'));
+        assert!(!computer.starts_with('T'));
+    }
+
+    #[test]
+    #[should_panic(expected = "computer names are two letters long, got \"T1\"")]
+    fn day_23_invalid_computer_name() {
+        Computer::new("T1");
+    }
+}
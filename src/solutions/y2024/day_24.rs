@@ -1,9 +1,8 @@
 use itertools::Itertools;
 use rustc_hash::{FxHashMap, FxHashSet};
-use std::fs::File;
-use std::io;
-use std::io::Write;
 use std::ops::Not;
+#[cfg(not(target_arch = "wasm32"))]
+use std::{fs::File, io, io::Write};
 
 pub fn solve_1(system: &str) -> u64 {
     let System {
@@ -42,7 +41,13 @@ pub fn solve_2(system: &str) -> String {
     let gates = System::new(system).gates;
 
     let first_bit = "z00";
-    let last_bit = "z45";
+    let last_bit = gates
+        .iter()
+        .map(|gate| gate.out.as_str())
+        .filter(|out| out.starts_with("z"))
+        .max()
+        .unwrap_or_default()
+        .to_string();
 
     // Z-gates are always the result of an XOR (apart from the last bit)
     let z_gate_not_xor = |gate: &Gate| {
@@ -175,6 +180,7 @@ enum GateType {
 }
 
 #[allow(dead_code)]
+#[cfg(not(target_arch = "wasm32"))]
 fn generate_graphviz_file(system: &str) -> Result<(), io::Error> {
     let mut file = File::create("graphviz/day_24.dot")?;
 
@@ -222,6 +228,7 @@ fn generate_graphviz_file(system: &str) -> Result<(), io::Error> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{answers, inputs};
 
     #[test]
     fn day_24_part_01_sample() {
@@ -294,9 +301,12 @@ mod tests {
 
     #[test]
     fn day_24_part_01_solution() {
-        let input = include_str!("../../inputs/day_24.txt").trim();
+        let Some(input) = inputs::load(2024, 24) else {
+            return;
+        };
+        let input = input.trim();
 
-        assert_eq!(36_035_961_805_936, solve_1(input));
+        assert_eq!(answers::expected(2024, 24, 1), solve_1(input).to_string());
     }
 
     #[test]
@@ -306,8 +316,11 @@ mod tests {
 
     #[test]
     fn day_24_part_02_solution() {
-        let input = include_str!("../../inputs/day_24.txt").trim();
+        let Some(input) = inputs::load(2024, 24) else {
+            return;
+        };
+        let input = input.trim();
 
-        assert_eq!("jqf,mdd,skh,wpd,wts,z11,z19,z37", solve_2(input));
+        assert_eq!(answers::expected(2024, 24, 2), solve_2(input).to_string());
     }
 }
@@ -0,0 +1,417 @@
+use itertools::Itertools;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+pub fn solve_1(schematics: &str) -> usize {
+    Schematics::from_str(schematics).unwrap().count_pairs()
+}
+
+pub fn solve_2() {
+    // Deliver The Chronicle
+}
+
+/// Counting tables larger than this fall back to testing every lock against every key.
+const MAX_TABLE_SIZE: usize = 1 << 20;
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum SchematicError {
+    Empty,
+    /// A row is not as wide as the first row of its schematic.
+    Ragged {
+        row: usize,
+    },
+    InvalidTile(char),
+    /// Neither the top row nor the bottom row is entirely filled.
+    NotLockOrKey,
+    /// A pin does not extend from its base in one piece.
+    BrokenPin {
+        column: usize,
+    },
+    /// The schematic is not as large as the first schematic.
+    MismatchedSize {
+        width: usize,
+        height: usize,
+    },
+}
+
+impl Display for SchematicError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SchematicError::Empty => write!(f, "empty schematic"),
+            SchematicError::Ragged { row } => write!(f, "row {row} has a different width"),
+            SchematicError::InvalidTile(tile) => write!(f, "invalid tile {tile:?}"),
+            SchematicError::NotLockOrKey => write!(f, "neither a lock nor a key"),
+            SchematicError::BrokenPin { column } => write!(f, "pin in column {column} is broken"),
+            SchematicError::MismatchedSize { width, height } => {
+                write!(
+                    f,
+                    "schematic of {width}x{height} differs from the first one"
+                )
+            }
+        }
+    }
+}
+
+impl Error for SchematicError {}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Schematics {
+    locks: Vec<Schematic>,
+    keys: Vec<Schematic>,
+    width: usize,
+    height: usize,
+}
+
+impl FromStr for Schematics {
+    type Err = SchematicError;
+
+    fn from_str(schematics: &str) -> Result<Self, Self::Err> {
+        let schematics: Vec<Schematic> = schematics
+            .split("\n\n")
+            .map(Schematic::from_str)
+            .try_collect()?;
+
+        let (width, height) = schematics
+            .first()
+            .map(|schematic| (schematic.width, schematic.height))
+            .unwrap_or_default();
+        if let Some(schematic) = schematics
+            .iter()
+            .find(|schematic| (schematic.width, schematic.height) != (width, height))
+        {
+            return Err(SchematicError::MismatchedSize {
+                width: schematic.width,
+                height: schematic.height,
+            });
+        }
+
+        let (locks, keys) = schematics
+            .into_iter()
+            .partition(|schematic| schematic.schematic_type == SchematicType::Lock);
+
+        Ok(Self {
+            locks,
+            keys,
+            width,
+            height,
+        })
+    }
+}
+
+impl Schematics {
+    pub fn locks(&self) -> &[Schematic] {
+        &self.locks
+    }
+
+    pub fn keys(&self) -> &[Schematic] {
+        &self.keys
+    }
+
+    /// Every fitting lock and key, as indices into `locks` and `keys`.
+    pub fn matching_pairs(&self) -> Vec<(usize, usize)> {
+        self.locks
+            .iter()
+            .enumerate()
+            .flat_map(|(lock_idx, lock)| {
+                self.keys
+                    .iter()
+                    .positions(|key| lock.fits(key))
+                    .map(move |key_idx| (lock_idx, key_idx))
+            })
+            .collect()
+    }
+
+    /// Counts fitting lock and key pairs without trying every combination.
+    ///
+    /// A key fits when none of its pins is higher than the room a lock leaves in that column,
+    /// so tallying the keys per pin heights and summing those tallies over all smaller heights,
+    /// one column at a time, tells how many keys fit any possible lock.
+    pub fn count_pairs(&self) -> usize {
+        // Pins may fill their whole column, leaving no room at all for the other side
+        let room = self.height.checked_sub(2);
+        let sides = self.height;
+        let size = sides.checked_pow(self.width as u32).unwrap_or(usize::MAX);
+
+        if size > MAX_TABLE_SIZE {
+            return self.matching_pairs().len();
+        }
+
+        let index = |pins: &mut dyn Iterator<Item = usize>| {
+            pins.fold(0, |idx, height| idx * sides + height)
+        };
+
+        let mut table = vec![0; size];
+        self.keys
+            .iter()
+            .for_each(|key| table[index(&mut key.pins.iter().copied())] += 1);
+
+        let mut stride = 1;
+        for _ in 0..self.width {
+            for idx in 0..size {
+                if (idx / stride) % sides > 0 {
+                    table[idx] += table[idx - stride];
+                }
+            }
+            stride *= sides;
+        }
+
+        self.locks
+            .iter()
+            .filter_map(|lock| {
+                let rooms: Option<Vec<usize>> = lock
+                    .pins
+                    .iter()
+                    .map(|&pin| room?.checked_sub(pin))
+                    .collect();
+                rooms.map(|rooms| table[index(&mut rooms.into_iter())])
+            })
+            .sum()
+    }
+}
+
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct Schematic {
+    pins: Vec<usize>,
+    /// Filled tiles, one bit per tile, row by row.
+    mask: Vec<u64>,
+    width: usize,
+    height: usize,
+    schematic_type: SchematicType,
+}
+
+impl FromStr for Schematic {
+    type Err = SchematicError;
+
+    fn from_str(schematic: &str) -> Result<Self, Self::Err> {
+        let schematic = schematic
+            .trim()
+            .split('\n')
+            .map(|line| {
+                line.trim()
+                    .chars()
+                    .map(|tile| match tile {
+                        '#' => Ok(true),
+                        '.' => Ok(false),
+                        _ => Err(SchematicError::InvalidTile(tile)),
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let height = schematic.len();
+        let width = schematic.first().map(|row| row.len()).unwrap_or(0);
+        if width == 0 {
+            return Err(SchematicError::Empty);
+        }
+        if let Some(row) = schematic.iter().position(|row| row.len() != width) {
+            return Err(SchematicError::Ragged { row });
+        }
+
+        let schematic_type = if schematic[0].iter().all(|&filled| filled) {
+            SchematicType::Lock
+        } else if schematic[height - 1].iter().all(|&filled| filled) {
+            SchematicType::Key
+        } else {
+            return Err(SchematicError::NotLockOrKey);
+        };
+
+        let rows = match schematic_type {
+            SchematicType::Lock => (0..height).collect_vec(),
+            SchematicType::Key => (0..height).rev().collect_vec(),
+        };
+
+        let pins = (0..width)
+            .map(|column| {
+                let filled = rows
+                    .iter()
+                    .take_while(|&&row| schematic[row][column])
+                    .count();
+                let total = (0..height).filter(|&row| schematic[row][column]).count();
+
+                (filled == total)
+                    .then_some(filled - 1)
+                    .ok_or(SchematicError::BrokenPin { column })
+            })
+            .try_collect()?;
+
+        let mut mask = vec![0; (width * height).div_ceil(64)];
+        schematic
+            .iter()
+            .flatten()
+            .enumerate()
+            .filter(|(_, &filled)| filled)
+            .for_each(|(bit, _)| mask[bit / 64] |= 1 << (bit % 64));
+
+        Ok(Self {
+            pins,
+            mask,
+            width,
+            height,
+            schematic_type,
+        })
+    }
+}
+
+impl Schematic {
+    pub fn pins(&self) -> &[usize] {
+        &self.pins
+    }
+
+    pub fn is_lock(&self) -> bool {
+        self.schematic_type == SchematicType::Lock
+    }
+
+    /// Whether both fit together, meaning none of their tiles overlap.
+    pub fn fits(&self, other: &Schematic) -> bool {
+        self.mask
+            .iter()
+            .zip(&other.mask)
+            .all(|(mask, other)| mask & other == 0)
+    }
+}
+
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+enum SchematicType {
+    Lock,
+    Key,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers, inputs};
+
+    #[test]
+    fn day_25_part_01_sample() {
+        let sample = "\
+                #####\n\
+                .####\n\
+                .####\n\
+                .####\n\
+                .#.#.\n\
+                .#...\n\
+                .....\n\
+                \n\
+                #####\n\
+                ##.##\n\
+                .#.##\n\
+                ...##\n\
+                ...#.\n\
+                ...#.\n\
+                .....\n\
+                \n\
+                .....\n\
+                #....\n\
+                #....\n\
+                #...#\n\
+                #.#.#\n\
+                #.###\n\
+                #####\n\
+                \n\
+                .....\n\
+                .....\n\
+                #.#..\n\
+                ###..\n\
+                ###.#\n\
+                ###.#\n\
+                #####\n\
+                \n\
+                .....\n\
+                .....\n\
+                .....\n\
+                #....\n\
+                #.#..\n\
+                #.#.#\n\
+                #####\
+            ";
+
+        assert_eq!(3, solve_1(sample));
+    }
+
+    #[test]
+    fn day_25_part_01_solution() {
+        let Some(input) = inputs::load(2024, 25) else {
+            return;
+        };
+        let input = input.trim();
+
+        assert_eq!(answers::expected(2024, 25, 1), solve_1(input).to_string());
+    }
+
+    #[test]
+    fn day_25_part_02_sample() {
+        solve_2();
+    }
+
+    #[test]
+    fn day_25_part_02_solution() {
+        solve_2();
+    }
+
+    #[test]
+    fn day_25_schematic_sizes() {
+        let sample = "\
+                ###\n\
+                #.#\n\
+                ...\n\
+                ...\n\
+                \n\
+                ...\n\
+                ...\n\
+                .#.\n\
+                ###\n\
+                \n\
+                ...\n\
+                #..\n\
+                ##.\n\
+                ###\
+            ";
+        let schematics = Schematics::from_str(sample).unwrap();
+
+        assert_eq!(vec![1, 0, 1], schematics.locks()[0].pins());
+        assert_eq!(vec![(0, 0)], schematics.matching_pairs());
+        assert_eq!(1, schematics.count_pairs());
+    }
+
+    #[test]
+    fn day_25_full_pins() {
+        let full_key = "###\n...\n...\n...\n\n#..\n#..\n#..\n###\n\n###\n#..\n...\n...";
+        let full_lock =
+            "###\n#..\n#..\n#..\n\n###\n...\n...\n...\n\n...\n...\n...\n###\n\n...\n...\n..#\n###";
+
+        for (pairs, sample) in [(0, full_key), (2, full_lock)] {
+            let schematics = Schematics::from_str(sample).unwrap();
+
+            assert_eq!(pairs, schematics.matching_pairs().len());
+            assert_eq!(pairs, schematics.count_pairs());
+        }
+    }
+
+    #[test]
+    fn day_25_schematic_errors() {
+        assert_eq!(
+            Err(SchematicError::NotLockOrKey),
+            Schematic::from_str("#.#\n...\n#.#")
+        );
+        assert_eq!(
+            Err(SchematicError::BrokenPin { column: 1 }),
+            Schematic::from_str("###\n#.#\n.#.\n...")
+        );
+        assert_eq!(
+            Err(SchematicError::Ragged { row: 1 }),
+            Schematic::from_str("###\n#.\n...")
+        );
+        assert_eq!(
+            Err(SchematicError::InvalidTile('x')),
+            Schematic::from_str("###\n#x#\n...")
+        );
+        assert_eq!(
+            Err(SchematicError::MismatchedSize {
+                width: 2,
+                height: 2
+            }),
+            Schematics::from_str("###\n...\n\n..\n##")
+        );
+    }
+}
@@ -0,0 +1,489 @@
+use crate::answers::User;
+use crate::client;
+use crate::client::{ClientError, HttpClient, RateLimited, UreqClient};
+use crate::json_file;
+use crate::json_file::JsonFileError;
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::error;
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const REQUEST_INTERVAL: Duration = Duration::from_secs(5);
+
+lazy_static! {
+    static ref ARTICLE_RE: Regex = Regex::new(r"(?s)<article>(.*?)</article>").unwrap();
+    static ref TAG_RE: Regex = Regex::new(r"<[^>]*>").unwrap();
+    static ref LEFT_TO_WAIT_RE: Regex =
+        Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+}
+
+/// How Advent of Code judged a submitted answer.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint in which direction.
+    Wrong,
+    /// Answered too recently, nothing was judged.
+    Wait(Duration),
+    /// The part was solved before, or is not unlocked yet.
+    WrongLevel,
+}
+
+impl Outcome {
+    /// Classifies the page returned after submitting, going by the message in its `<article>`.
+    pub fn parse(page: &str) -> Option<Self> {
+        let message = ARTICLE_RE.captures(page)?.get(1)?.as_str();
+        let message = TAG_RE.replace_all(message, "");
+
+        let outcome = if message.contains("That's the right answer") {
+            Outcome::Correct
+        } else if message.contains("You gave an answer too recently") {
+            Outcome::Wait(Self::left_to_wait(&message).unwrap_or(Duration::from_secs(60)))
+        } else if message.contains("That's not the right answer") {
+            if message.contains("your answer is too high") {
+                Outcome::TooHigh
+            } else if message.contains("your answer is too low") {
+                Outcome::TooLow
+            } else {
+                Outcome::Wrong
+            }
+        } else if message.contains("You don't seem to be solving the right level") {
+            Outcome::WrongLevel
+        } else {
+            return None;
+        };
+
+        Some(outcome)
+    }
+
+    /// Reads e.g. "You have 1m 23s left to wait."
+    fn left_to_wait(message: &str) -> Option<Duration> {
+        let captures = LEFT_TO_WAIT_RE.captures(message)?;
+        let minutes = captures
+            .get(1)
+            .map_or(Some(0), |m| m.as_str().parse().ok())?;
+        let seconds: u64 = captures.get(2)?.as_str().parse().ok()?;
+
+        Some(Duration::from_secs(minutes * 60 + seconds))
+    }
+}
+
+/// Reasons to not even send an answer, as the outcome is already known.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Rejected {
+    Solved {
+        answer: String,
+    },
+    AlreadyGuessed,
+    /// At most the highest answer known to be too low.
+    TooLow {
+        bound: i128,
+    },
+    /// At least the lowest answer known to be too high.
+    TooHigh {
+        bound: i128,
+    },
+    Wait(Duration),
+}
+
+impl Display for Rejected {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Rejected::Solved { answer } => write!(f, "already solved with {answer}"),
+            Rejected::AlreadyGuessed => write!(f, "already guessed wrong before"),
+            Rejected::TooLow { bound } => write!(f, "too low, {bound} already was"),
+            Rejected::TooHigh { bound } => write!(f, "too high, {bound} already was"),
+            Rejected::Wait(left) => write!(f, "submitted too recently, wait {}s", left.as_secs()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum SubmitError {
+    MissingSession,
+    Rejected(Rejected),
+    Client(ClientError),
+    Status(u16),
+    /// Advent of Code answered with a page that could not be made sense of.
+    Unrecognised(String),
+    Guesses(JsonFileError),
+}
+
+impl Display for SubmitError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubmitError::MissingSession => write!(f, "no session to submit with"),
+            SubmitError::Rejected(rejected) => write!(f, "not submitted, {rejected}"),
+            SubmitError::Client(e) => write!(f, "{e}"),
+            SubmitError::Status(status) => write!(f, "submission answered with status {status}"),
+            SubmitError::Unrecognised(page) => write!(f, "unrecognised response: {page}"),
+            SubmitError::Guesses(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl error::Error for SubmitError {}
+
+/// Everything learned from earlier submissions of a single part.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PartGuesses {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub correct: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub wrong: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub too_low: Option<i128>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub too_high: Option<i128>,
+}
+
+impl PartGuesses {
+    fn check(&self, answer: &str) -> Result<(), Rejected> {
+        if let Some(correct) = &self.correct {
+            return Err(Rejected::Solved {
+                answer: correct.clone(),
+            });
+        }
+        if self.wrong.iter().any(|wrong| wrong == answer) {
+            return Err(Rejected::AlreadyGuessed);
+        }
+
+        if let Ok(answer) = answer.parse::<i128>() {
+            match (self.too_low, self.too_high) {
+                (Some(bound), _) if answer <= bound => return Err(Rejected::TooLow { bound }),
+                (_, Some(bound)) if answer >= bound => return Err(Rejected::TooHigh { bound }),
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    fn record(&mut self, answer: &str, outcome: &Outcome) {
+        let number = answer.parse::<i128>().ok();
+
+        match outcome {
+            Outcome::Correct => self.correct = Some(answer.to_string()),
+            Outcome::TooLow => {
+                self.too_low = self.too_low.max(number);
+                self.wrong.push(answer.to_string());
+            }
+            Outcome::TooHigh => {
+                self.too_high = match (self.too_high, number) {
+                    (Some(bound), Some(number)) => Some(bound.min(number)),
+                    (bound, number) => bound.or(number),
+                };
+                self.wrong.push(answer.to_string());
+            }
+            Outcome::Wrong => self.wrong.push(answer.to_string()),
+            Outcome::Wait(_) | Outcome::WrongLevel => {}
+        }
+    }
+}
+
+/// Previous submissions of a single user, kept next to their inputs.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Guesses {
+    /// Seconds since the epoch until which Advent of Code asked not to submit anything.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    wait_until: Option<u64>,
+    #[serde(default)]
+    parts: BTreeMap<u16, BTreeMap<u8, BTreeMap<u8, PartGuesses>>>,
+}
+
+impl Guesses {
+    pub fn load(path: &Path) -> Result<Self, JsonFileError> {
+        json_file::load(path)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), JsonFileError> {
+        json_file::save(self, path)
+    }
+
+    pub fn part(&self, year: u16, day: u8, part: u8) -> Option<&PartGuesses> {
+        self.parts.get(&year)?.get(&day)?.get(&part)
+    }
+
+    pub fn check(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<(), Rejected> {
+        let left = self.wait_until.unwrap_or(0).saturating_sub(now());
+        if left > 0 {
+            return Err(Rejected::Wait(Duration::from_secs(left)));
+        }
+
+        self.part(year, day, part)
+            .map_or(Ok(()), |guesses| guesses.check(answer))
+    }
+
+    pub fn record(&mut self, year: u16, day: u8, part: u8, answer: &str, outcome: &Outcome) {
+        match outcome {
+            Outcome::Wait(left) => self.wait_until = Some(now() + left.as_secs()),
+            _ => self.wait_until = None,
+        }
+
+        self.parts
+            .entry(year)
+            .or_default()
+            .entry(day)
+            .or_default()
+            .entry(part)
+            .or_default()
+            .record(answer, outcome);
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
+/// Submits answers, refusing the ones earlier submissions already tell the outcome of.
+pub struct Submitter<C = RateLimited<UreqClient>> {
+    base_url: String,
+    session: Option<String>,
+    client: C,
+    guesses: Guesses,
+    path: PathBuf,
+}
+
+impl Submitter {
+    pub fn for_user(user: &User) -> Result<Self, SubmitError> {
+        Self::new(
+            client::BASE_URL,
+            user.inputs.session().map(str::to_string),
+            RateLimited::new(UreqClient::new(), REQUEST_INTERVAL),
+            user.inputs.cache().join("guesses.json"),
+        )
+    }
+}
+
+impl<C: HttpClient> Submitter<C> {
+    pub fn new(
+        base_url: &str,
+        session: Option<String>,
+        client: C,
+        path: impl Into<PathBuf>,
+    ) -> Result<Self, SubmitError> {
+        let path = path.into();
+
+        Ok(Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+            client,
+            guesses: Guesses::load(&path).map_err(SubmitError::Guesses)?,
+            path,
+        })
+    }
+
+    pub fn guesses(&self) -> &Guesses {
+        &self.guesses
+    }
+
+    pub fn submit(
+        &mut self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+    ) -> Result<Outcome, SubmitError> {
+        let answer = answer.trim();
+        let session = self.session.as_ref().ok_or(SubmitError::MissingSession)?;
+        self.guesses
+            .check(year, day, part, answer)
+            .map_err(SubmitError::Rejected)?;
+
+        let url = format!("{}/{}/day/{}/answer", self.base_url, year, day);
+        let level = part.to_string();
+        let response = self
+            .client
+            .post(&url, session, &[("level", &level), ("answer", answer)])
+            .map_err(SubmitError::Client)?;
+        if !response.is_success() {
+            return Err(SubmitError::Status(response.status));
+        }
+        let outcome =
+            Outcome::parse(&response.body).ok_or(SubmitError::Unrecognised(response.body))?;
+
+        self.guesses.record(year, day, part, answer, &outcome);
+        self.guesses
+            .save(&self.path)
+            .map_err(SubmitError::Guesses)?;
+
+        Ok(outcome)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::mock::MockServer;
+    use std::{env, fs, process};
+
+    fn page(message: &str) -> String {
+        format!("<html><body><main>\n<article><p>{message}</p></article>\n</main></body></html>")
+    }
+
+    fn guesses_path(name: &str) -> PathBuf {
+        let path = env::temp_dir().join(format!("aoc-guesses-{}-{}.json", process::id(), name));
+        let _ = fs::remove_file(&path);
+        path
+    }
+
+    #[test]
+    fn submit_outcomes() {
+        assert_eq!(
+            Some(Outcome::Correct),
+            Outcome::parse(&page("That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer."))
+        );
+        assert_eq!(
+            Some(Outcome::TooHigh),
+            Outcome::parse(&page("That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data."))
+        );
+        assert_eq!(
+            Some(Outcome::TooLow),
+            Outcome::parse(&page(
+                "That's not the right answer; your answer is too low."
+            ))
+        );
+        assert_eq!(
+            Some(Outcome::Wrong),
+            Outcome::parse(&page("That's not the right answer.  If you're stuck, make sure you're using the full input data."))
+        );
+        assert_eq!(
+            Some(Outcome::Wait(Duration::from_secs(83))),
+            Outcome::parse(&page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 23s left to wait."))
+        );
+        assert_eq!(
+            Some(Outcome::Wait(Duration::from_secs(9))),
+            Outcome::parse(&page(
+                "You gave an answer too recently.  You have 9s left to wait."
+            ))
+        );
+        assert_eq!(
+            Some(Outcome::WrongLevel),
+            Outcome::parse(&page(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            ))
+        );
+        assert_eq!(
+            None,
+            Outcome::parse("<html>Puzzle inputs differ by user.</html>")
+        );
+    }
+
+    #[test]
+    fn submit_remembers_guesses() {
+        let server = MockServer::start(|request| {
+            let message = match request.body.as_str() {
+                "level=1&answer=100" => "That's not the right answer; your answer is too high.",
+                "level=1&answer=10" => "That's not the right answer; your answer is too low.",
+                "level=1&answer=42" => "That's the right answer!",
+                _ => "That's not the right answer.",
+            };
+            (200, page(message))
+        });
+        let path = guesses_path("remember");
+        let url = server.url("");
+        let submitter = || Submitter::new(&url, Some("abc".to_string()), UreqClient::new(), &path);
+
+        let mut submitter_1 = submitter().unwrap();
+        assert_eq!(
+            Outcome::TooHigh,
+            submitter_1.submit(2024, 1, 1, "100").unwrap()
+        );
+        assert_eq!(
+            Outcome::TooLow,
+            submitter_1.submit(2024, 1, 1, "10").unwrap()
+        );
+        assert_eq!(
+            Outcome::Wrong,
+            submitter_1.submit(2024, 1, 1, "50").unwrap()
+        );
+
+        // Reloaded from disk, nothing below gets sent
+        let mut submitter_2 = submitter().unwrap();
+        assert!(matches!(
+            submitter_2.submit(2024, 1, 1, "50"),
+            Err(SubmitError::Rejected(Rejected::AlreadyGuessed))
+        ));
+        assert!(matches!(
+            submitter_2.submit(2024, 1, 1, "101"),
+            Err(SubmitError::Rejected(Rejected::TooHigh { bound: 100 }))
+        ));
+        assert!(matches!(
+            submitter_2.submit(2024, 1, 1, "-3"),
+            Err(SubmitError::Rejected(Rejected::TooLow { bound: 10 }))
+        ));
+        assert_eq!(3, server.requests().len());
+
+        assert_eq!(
+            Outcome::Correct,
+            submitter_2.submit(2024, 1, 1, "42").unwrap()
+        );
+        assert!(matches!(
+            submitter_2.submit(2024, 1, 1, "43"),
+            Err(SubmitError::Rejected(Rejected::Solved { .. }))
+        ));
+        assert_eq!(
+            Some(&PartGuesses {
+                correct: Some("42".to_string()),
+                wrong: vec!["100".to_string(), "10".to_string(), "50".to_string()],
+                too_low: Some(10),
+                too_high: Some(100),
+            }),
+            submitter_2.guesses().part(2024, 1, 1)
+        );
+
+        let requests = server.requests();
+        assert_eq!(4, requests.len());
+        assert_eq!("/2024/day/1/answer", requests[3].path);
+        assert_eq!(Some("session=abc"), requests[3].header("cookie"));
+    }
+
+    #[test]
+    fn submit_throttled_locally() {
+        let server = MockServer::start(|_| {
+            (
+                200,
+                page("You gave an answer too recently.  You have 5m 0s left to wait."),
+            )
+        });
+        let path = guesses_path("throttle");
+        let mut submitter = Submitter::new(
+            &server.url(""),
+            Some("abc".to_string()),
+            UreqClient::new(),
+            &path,
+        )
+        .unwrap();
+
+        assert_eq!(
+            Outcome::Wait(Duration::from_secs(300)),
+            submitter.submit(2024, 2, 1, "390").unwrap()
+        );
+        assert!(matches!(
+            submitter.submit(2024, 3, 2, "48"),
+            Err(SubmitError::Rejected(Rejected::Wait(_)))
+        ));
+        assert_eq!(1, server.requests().len());
+    }
+
+    #[test]
+    fn submit_without_session() {
+        let path = guesses_path("session");
+        let mut submitter =
+            Submitter::new("http://127.0.0.1:1", None, UreqClient::new(), &path).unwrap();
+
+        assert!(matches!(
+            submitter.submit(2024, 1, 1, "11"),
+            Err(SubmitError::MissingSession)
+        ));
+    }
+}
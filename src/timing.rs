@@ -0,0 +1,156 @@
+use crate::json_file;
+use crate::json_file::JsonFileError;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Timings {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Timings {
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        let samples = samples.iter().sorted().collect_vec();
+
+        Some(Self {
+            min: **samples.first()?,
+            median: *samples[samples.len() / 2],
+            max: **samples.last()?,
+        })
+    }
+}
+
+/// Times `runs` runs of `f`, after running it `warm_up` times without timing it.
+pub fn measure<T>(runs: usize, warm_up: usize, mut f: impl FnMut() -> T) -> Option<Timings> {
+    (0..warm_up).for_each(|_| {
+        std::hint::black_box(f());
+    });
+
+    let samples = (0..runs)
+        .map(|_| {
+            let start = Instant::now();
+            std::hint::black_box(f());
+            start.elapsed()
+        })
+        .collect_vec();
+
+    Timings::from_samples(&samples)
+}
+
+/// Median times in nanoseconds, by day (e.g. `2024-07`) and step (`parse`, `part1` or `part2`).
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Baseline {
+    medians: BTreeMap<String, BTreeMap<String, u64>>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Comparison {
+    /// Nothing to compare with in the baseline.
+    New,
+    /// Within the threshold, with the relative change, e.g. `-0.05` being 5% faster.
+    Within(f64),
+    Regressed(f64),
+}
+
+impl Baseline {
+    pub fn load(path: &Path) -> Result<Self, JsonFileError> {
+        json_file::load(path)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), JsonFileError> {
+        json_file::save(self, path)
+    }
+
+    pub fn get(&self, day: &str, step: &str) -> Option<Duration> {
+        self.medians
+            .get(day)?
+            .get(step)
+            .map(|&ns| Duration::from_nanos(ns))
+    }
+
+    pub fn set(&mut self, day: &str, step: &str, median: Duration) {
+        self.medians
+            .entry(day.to_string())
+            .or_default()
+            .insert(step.to_string(), median.as_nanos() as u64);
+    }
+
+    /// Compares a median to the baseline, regressing when slower by more than `threshold`,
+    /// e.g. `0.1` allowing it to be 10% slower.
+    pub fn compare(&self, day: &str, step: &str, median: Duration, threshold: f64) -> Comparison {
+        let Some(baseline) = self.get(day, step).filter(|baseline| !baseline.is_zero()) else {
+            return Comparison::New;
+        };
+        let change = median.as_secs_f64() / baseline.as_secs_f64() - 1.0;
+
+        if change > threshold {
+            Comparison::Regressed(change)
+        } else {
+            Comparison::Within(change)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timing_samples() {
+        let ms = Duration::from_millis;
+
+        assert_eq!(
+            Some(Timings {
+                min: ms(1),
+                median: ms(3),
+                max: ms(9)
+            }),
+            Timings::from_samples(&[ms(9), ms(1), ms(3), ms(2), ms(4)])
+        );
+        assert_eq!(None, Timings::from_samples(&[]));
+
+        let mut calls = 0;
+        assert!(measure(5, 2, || calls += 1).is_some());
+        assert_eq!(7, calls);
+    }
+
+    #[test]
+    fn timing_baseline() {
+        let ms = Duration::from_millis;
+        let mut baseline = Baseline::default();
+        baseline.set("2024-07", "part1", ms(100));
+
+        assert_eq!(
+            Comparison::Within(0.05),
+            round(baseline.compare("2024-07", "part1", ms(105), 0.1))
+        );
+        assert_eq!(
+            Comparison::Within(-0.5),
+            round(baseline.compare("2024-07", "part1", ms(50), 0.1))
+        );
+        assert_eq!(
+            Comparison::Regressed(0.2),
+            round(baseline.compare("2024-07", "part1", ms(120), 0.1))
+        );
+        assert_eq!(
+            Comparison::New,
+            baseline.compare("2024-07", "part2", ms(1), 0.1)
+        );
+    }
+
+    fn round(comparison: Comparison) -> Comparison {
+        let round = |change: f64| (change * 100.0).round() / 100.0;
+
+        match comparison {
+            Comparison::New => Comparison::New,
+            Comparison::Within(change) => Comparison::Within(round(change)),
+            Comparison::Regressed(change) => Comparison::Regressed(round(change)),
+        }
+    }
+}
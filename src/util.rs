@@ -1,7 +1,12 @@
 pub mod clique;
 pub mod graph;
+pub mod linear;
+pub mod trie;
 pub mod union_find;
 
+use std::io;
+use std::io::BufRead;
+
 #[allow(dead_code)]
 pub const BASE_10: u32 = 10;
 
@@ -20,3 +25,19 @@ pub fn gcd(a: u64, b: u64) -> u64 {
 
     pair.0
 }
+
+/// Calls `f` on every non-empty line, without its line ending, reusing a single buffer
+/// so memory stays bounded by the longest line rather than the whole input.
+pub fn for_each_line(mut reader: impl BufRead, mut f: impl FnMut(&str)) -> io::Result<()> {
+    let mut line = String::new();
+
+    while reader.read_line(&mut line)? > 0 {
+        let trimmed = line.trim_end_matches(['\n', '\r']);
+        if !trimmed.trim().is_empty() {
+            f(trimmed);
+        }
+        line.clear();
+    }
+
+    Ok(())
+}
@@ -31,8 +31,8 @@ impl Clique {
     fn bron_kerbosch(
         &self,
         r: FxHashSet<usize>,
-        p: FxHashSet<usize>,
-        x: FxHashSet<usize>,
+        mut p: FxHashSet<usize>,
+        mut x: FxHashSet<usize>,
         max_cliques: &mut Vec<FxHashSet<usize>>,
     ) {
         if p.is_empty() && x.is_empty() {
@@ -41,8 +41,12 @@ impl Clique {
         }
 
         let u = *p.union(&x).next().unwrap();
+        let candidates = p
+            .difference(self.edges.get(&u).unwrap_or(&FxHashSet::default()))
+            .copied()
+            .collect::<Vec<_>>();
 
-        for &v in p.difference(self.edges.get(&u).unwrap_or(&FxHashSet::default())) {
+        for v in candidates {
             let mut next_r = r.clone();
             next_r.insert(v);
 
@@ -56,6 +60,10 @@ impl Clique {
                 .collect();
 
             self.bron_kerbosch(next_r, next_p, next_x, max_cliques);
+
+            // Every clique containing "v" has been reported, exclude it from here on
+            p.remove(&v);
+            x.insert(v);
         }
     }
 
@@ -97,8 +97,8 @@ where
     N: Ord + Eq + Hash + Clone,
     W: Copy + Ord + Default + Add<Output = W> + UpperBounded,
 {
-    pub fn distance(&self, node: &N) -> W {
-        self.distances[node]
+    pub fn distance(&self, node: &N) -> Option<W> {
+        self.distances.get(node).copied()
     }
 
     pub fn shortest_paths(&self, to: &N) -> Vec<Vec<N>> {
@@ -0,0 +1,275 @@
+use std::ops::RangeInclusive;
+
+/// Returned whenever an intermediate result no longer fits in an `i128`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Overflow;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Solutions {
+    /// No integer solution exists.
+    None,
+    /// Exactly one solution, and it is integer.
+    Unique(i128, i128),
+    /// Every integer point on a line is a solution.
+    Line(Line),
+    /// The system is all zeroes, any point is a solution.
+    Any,
+}
+
+/// The integer points `(x0 + dx * t, y0 + dy * t)` for any integer `t`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Line {
+    pub x0: i128,
+    pub y0: i128,
+    pub dx: i128,
+    pub dy: i128,
+}
+
+impl Line {
+    pub fn at(&self, t: i128) -> Result<(i128, i128), Overflow> {
+        let x = add(self.x0, mul(self.dx, t)?)?;
+        let y = add(self.y0, mul(self.dy, t)?)?;
+
+        Ok((x, y))
+    }
+
+    /// Range of `t` for which both coordinates stay within their bounds, if any.
+    pub fn t_range(
+        &self,
+        x_bounds: RangeInclusive<i128>,
+        y_bounds: RangeInclusive<i128>,
+    ) -> Option<RangeInclusive<i128>> {
+        let (x_min, x_max) = Self::t_bounds(self.x0, self.dx, x_bounds)?;
+        let (y_min, y_max) = Self::t_bounds(self.y0, self.dy, y_bounds)?;
+
+        let range = x_min.max(y_min)..=x_max.min(y_max);
+        (!range.is_empty()).then_some(range)
+    }
+
+    fn t_bounds(start: i128, delta: i128, bounds: RangeInclusive<i128>) -> Option<(i128, i128)> {
+        let (low, high) = bounds.into_inner();
+
+        match delta.signum() {
+            0 => (low..=high)
+                .contains(&start)
+                .then_some((i128::MIN, i128::MAX)),
+            1 => Some((
+                ceil_div(low.saturating_sub(start), delta),
+                floor_div(high.saturating_sub(start), delta),
+            )),
+            _ => Some((
+                ceil_div(high.saturating_sub(start), delta),
+                floor_div(low.saturating_sub(start), delta),
+            )),
+        }
+    }
+}
+
+/// Returns `(g, x, y)` such that `a * x + b * y = g = gcd(a, b)`, with `g` non-negative.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_s, mut s) = (1, 0);
+    let (mut old_t, mut t) = (0, 1);
+
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_s, s) = (s, old_s - quotient * s);
+        (old_t, t) = (t, old_t - quotient * t);
+    }
+
+    if old_r < 0 {
+        (-old_r, -old_s, -old_t)
+    } else {
+        (old_r, old_s, old_t)
+    }
+}
+
+pub fn determinant(matrix: [[i128; 2]; 2]) -> Result<i128, Overflow> {
+    sub(
+        mul(matrix[0][0], matrix[1][1])?,
+        mul(matrix[0][1], matrix[1][0])?,
+    )
+}
+
+/// Solves `a * x + b * y = c` over the integers.
+pub fn solve_diophantine(a: i128, b: i128, c: i128) -> Result<Solutions, Overflow> {
+    if a == 0 && b == 0 {
+        return Ok(if c == 0 {
+            Solutions::Any
+        } else {
+            Solutions::None
+        });
+    }
+
+    let (g, s, t) = extended_gcd(a, b);
+    if c % g != 0 {
+        return Ok(Solutions::None);
+    }
+
+    let scale = c / g;
+    Ok(Solutions::Line(Line {
+        x0: mul(s, scale)?,
+        y0: mul(t, scale)?,
+        dx: b / g,
+        dy: -a / g,
+    }))
+}
+
+/// Solves the system `matrix * (x, y) = rhs` over the integers.
+///
+/// A non-zero determinant leaves Cramer's rule, otherwise both equations are either
+/// contradictory or carry the same information, reducing to a single Diophantine equation.
+pub fn solve_2x2(matrix: [[i128; 2]; 2], rhs: [i128; 2]) -> Result<Solutions, Overflow> {
+    let det = determinant(matrix)?;
+
+    if det != 0 {
+        let x = determinant([[rhs[0], matrix[0][1]], [rhs[1], matrix[1][1]]])?;
+        let y = determinant([[matrix[0][0], rhs[0]], [matrix[1][0], rhs[1]]])?;
+
+        return Ok(if x % det == 0 && y % det == 0 {
+            Solutions::Unique(x / det, y / det)
+        } else {
+            Solutions::None
+        });
+    }
+
+    // Both rows are proportional, they agree if the right-hand side is scaled the same way
+    let consistent = determinant([[matrix[0][0], rhs[0]], [matrix[1][0], rhs[1]]])? == 0
+        && determinant([[matrix[0][1], rhs[0]], [matrix[1][1], rhs[1]]])? == 0;
+    if !consistent {
+        return Ok(Solutions::None);
+    }
+
+    match solve_diophantine(matrix[0][0], matrix[0][1], rhs[0])? {
+        Solutions::Any => solve_diophantine(matrix[1][0], matrix[1][1], rhs[1]),
+        solutions => Ok(solutions),
+    }
+}
+
+fn add(a: i128, b: i128) -> Result<i128, Overflow> {
+    a.checked_add(b).ok_or(Overflow)
+}
+
+fn sub(a: i128, b: i128) -> Result<i128, Overflow> {
+    a.checked_sub(b).ok_or(Overflow)
+}
+
+fn mul(a: i128, b: i128) -> Result<i128, Overflow> {
+    a.checked_mul(b).ok_or(Overflow)
+}
+
+fn floor_div(a: i128, b: i128) -> i128 {
+    let quotient = a / b;
+    if a % b != 0 && (a < 0) != (b < 0) {
+        quotient - 1
+    } else {
+        quotient
+    }
+}
+
+fn ceil_div(a: i128, b: i128) -> i128 {
+    let quotient = a / b;
+    if a % b != 0 && (a < 0) == (b < 0) {
+        quotient + 1
+    } else {
+        quotient
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn linear_extended_gcd() {
+        assert_eq!((6, -1, 1), extended_gcd(12, 18));
+        assert_eq!((6, 1, 1), extended_gcd(-12, 18));
+        assert_eq!((5, 0, 1), extended_gcd(0, 5));
+        assert_eq!((5, -1, 0), extended_gcd(-5, 0));
+        assert_eq!((0, 1, 0), extended_gcd(0, 0));
+    }
+
+    #[test]
+    fn linear_solve_2x2() {
+        assert_eq!(
+            Ok(Solutions::Unique(80, 40)),
+            solve_2x2([[94, 22], [34, 67]], [8_400, 5_400])
+        );
+        assert_eq!(Ok(Solutions::None), solve_2x2([[2, 0], [0, 2]], [3, 4]));
+
+        // All zeroes, with and without a right-hand side
+        assert_eq!(Ok(Solutions::Any), solve_2x2([[0, 0], [0, 0]], [0, 0]));
+        assert_eq!(Ok(Solutions::None), solve_2x2([[0, 0], [0, 0]], [0, 1]));
+
+        // A row of zeroes leaves the other row to decide
+        let line = Line {
+            x0: 3,
+            y0: 0,
+            dx: 0,
+            dy: -1,
+        };
+        assert_eq!(
+            Ok(Solutions::Line(line)),
+            solve_2x2([[0, 0], [1, 0]], [0, 3])
+        );
+        assert_eq!(Ok(Solutions::None), solve_2x2([[0, 0], [1, 0]], [1, 3]));
+
+        // A column of zeroes leaves that unknown free
+        let line = Line {
+            x0: 0,
+            y0: 2,
+            dx: 1,
+            dy: 0,
+        };
+        assert_eq!(
+            Ok(Solutions::Line(line)),
+            solve_2x2([[0, 2], [0, 3]], [4, 6])
+        );
+        assert_eq!(Ok(Solutions::None), solve_2x2([[0, 2], [0, 3]], [4, 7]));
+
+        // Proportional rows, consistent or not, and consistent but without integer solutions
+        assert!(matches!(
+            solve_2x2([[2, 4], [1, 2]], [10, 5]),
+            Ok(Solutions::Line(_))
+        ));
+        assert_eq!(Ok(Solutions::None), solve_2x2([[2, 4], [1, 2]], [10, 6]));
+        assert_eq!(Ok(Solutions::None), solve_2x2([[2, 4], [4, 8]], [3, 6]));
+        assert_eq!(Ok(Solutions::None), solve_2x2([[2, 4], [2, 4]], [5, 5]));
+
+        assert_eq!(
+            Err(Overflow),
+            solve_2x2([[i128::MAX, 2], [2, i128::MAX]], [0, 0])
+        );
+    }
+
+    #[test]
+    fn linear_t_range() {
+        let Ok(Solutions::Line(line)) = solve_2x2([[2, 4], [1, 2]], [10, 5]) else {
+            panic!("expected a line of solutions");
+        };
+        let range = line.t_range(0..=100, 0..=100).unwrap();
+        let mut points = range.map(|t| line.at(t).unwrap()).collect::<Vec<_>>();
+        points.sort();
+
+        assert_eq!(vec![(1, 2), (3, 1), (5, 0)], points);
+        assert_eq!(None, line.t_range(0..=0, 0..=0));
+
+        let horizontal = Line {
+            x0: 0,
+            y0: 3,
+            dx: 1,
+            dy: 0,
+        };
+        assert_eq!(Some(-2..=2), horizontal.t_range(-2..=2, 0..=5));
+        assert_eq!(None, horizontal.t_range(-2..=2, 4..=5));
+
+        let steep = Line {
+            x0: i128::MAX,
+            y0: 0,
+            dx: i128::MAX,
+            dy: 1,
+        };
+        assert_eq!(Err(Overflow), steep.at(2));
+    }
+}
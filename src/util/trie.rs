@@ -0,0 +1,62 @@
+use rustc_hash::FxHashMap;
+
+/// Prefix tree over the bytes of a set of words, each word identified by its insertion index.
+#[derive(Debug, Clone)]
+pub struct Trie {
+    nodes: Vec<TrieNode>,
+    len: usize,
+}
+
+impl Default for Trie {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct TrieNode {
+    children: FxHashMap<u8, usize>,
+    word: Option<usize>,
+}
+
+impl Trie {
+    pub fn new() -> Self {
+        Self {
+            nodes: vec![TrieNode::default()],
+            len: 0,
+        }
+    }
+
+    /// Inserts a word, returning its index, or the index it already had.
+    pub fn insert(&mut self, word: &str) -> usize {
+        let mut node = 0;
+
+        for &b in word.as_bytes() {
+            node = match self.nodes[node].children.get(&b) {
+                Some(&child) => child,
+                None => {
+                    self.nodes.push(TrieNode::default());
+                    let child = self.nodes.len() - 1;
+                    self.nodes[node].children.insert(b, child);
+                    child
+                }
+            };
+        }
+
+        *self.nodes[node].word.get_or_insert_with(|| {
+            self.len += 1;
+            self.len - 1
+        })
+    }
+
+    /// Every word that is a prefix of `text`, as `(length, index)`, shortest first.
+    pub fn prefixes<'a>(&'a self, text: &'a [u8]) -> impl Iterator<Item = (usize, usize)> + 'a {
+        text.iter()
+            .scan(0, |node, b| {
+                *node = *self.nodes[*node].children.get(b)?;
+                Some(*node)
+            })
+            .enumerate()
+            .filter_map(|(idx, node)| self.nodes[node].word.map(|word| (idx + 1, word)))
+    }
+}
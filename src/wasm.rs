@@ -0,0 +1,35 @@
+//! Solvers of every registered day of 2024, exported to JavaScript.
+//!
+//! Built with [wasm-pack](https://rustwasm.github.io/wasm-pack/) for the playground in `www/`:
+//! ```shell
+//! $ wasm-pack build --target web --out-dir www/pkg -- --features wasm
+//! ```
+
+use crate::registry;
+use wasm_bindgen::prelude::*;
+
+/// Solves a part of a day of 2024 for the given input, throwing if that day or part is not solved.
+#[wasm_bindgen]
+pub fn solve(day: u8, part: u8, input: &str) -> Result<String, String> {
+    solve_day(2024, day, part, input).ok_or_else(|| format!("day {day} part {part} is not solved"))
+}
+
+pub fn solve_day(year: u16, day: u8, part: u8, input: &str) -> Option<String> {
+    let solver = registry::find(year, day)?.solver(part)?;
+    Some(solver(input))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wasm_solve_day() {
+        let sample = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+
+        assert_eq!(Some("11".to_string()), solve_day(2024, 1, 1, sample));
+        assert_eq!(Some("31".to_string()), solve_day(2024, 1, 2, sample));
+        assert_eq!(None, solve_day(2024, 25, 2, sample));
+        assert_eq!(None, solve_day(2024, 26, 1, sample));
+    }
+}
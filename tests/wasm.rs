@@ -0,0 +1,23 @@
+//! Runs the exported solvers under Node, the default of `wasm-bindgen-test`:
+//! ```shell
+//! $ cargo test --target wasm32-unknown-unknown --features wasm --test wasm
+//! ```
+//! This needs `wasm-bindgen-test-runner` from `wasm-bindgen-cli` on the path,
+//! matching the version of `wasm-bindgen` in `Cargo.lock`.
+#![cfg(target_arch = "wasm32")]
+
+use advent_of_code_2024::wasm::solve;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+fn wasm_solves_samples() {
+    let locations = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+    let calibrations = "190: 10 19\n3267: 81 40 27\n83: 17 5\n156: 15 6\n7290: 6 8 6 15\n\
+        161011: 16 10 13\n192: 17 8 14\n21037: 9 7 18 13\n292: 11 6 16 20\n";
+
+    assert_eq!(Ok("11".to_string()), solve(1, 1, locations));
+    assert_eq!(Ok("31".to_string()), solve(1, 2, locations));
+    // Runs serially, there are no threads to spread the calibrations over
+    assert_eq!(Ok("11387".to_string()), solve(7, 2, calibrations));
+    assert!(solve(25, 2, "").is_err());
+}